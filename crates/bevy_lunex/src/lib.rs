@@ -94,6 +94,9 @@ impl PluginGroup for UiDefaultPlugins {
 pub mod logic;
 pub use logic::*;
 
+mod macros;
+pub use macros::ui;
+
 pub mod picking;
 pub use picking::*;
 
@@ -108,6 +111,7 @@ pub mod prelude {
 
     pub use super::Cursor2d;
     pub use super::actions;
+    pub use super::macros::ui;
 
     pub use super::logic::*;
 
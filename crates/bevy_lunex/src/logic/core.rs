@@ -80,21 +80,30 @@ fn on_ui_click_commands_system(mut events: EventReader<UiClickEvent>, mut comman
 #[derive(Component, Debug, Clone, PartialEq, Eq)]
 pub struct OnUiClickDespawn {
     pub target: Option<Entity>,
+    /// Play [`Outro`] animations before despawning, see [`UiDespawnCommandsExt::despawn_with_outro`]
+    pub outro: bool,
 }
 impl OnUiClickDespawn {
     /// The entity will create the event for itself and not other entities.
-    pub const SELF: OnUiClickDespawn = OnUiClickDespawn { target: None };
+    pub const SELF: OnUiClickDespawn = OnUiClickDespawn { target: None, outro: false };
     /// Specify the entity you want to create events for.
     pub fn new(entity: Entity) -> Self {
         OnUiClickDespawn {
-            target: Some(entity)
+            target: Some(entity),
+            outro: false,
         }
     }
+    /// Replaces the outro with a new value.
+    pub fn outro(mut self, outro: bool) -> Self {
+        self.outro = outro;
+        self
+    }
 }
 fn on_ui_click_despawn_system(mut events: EventReader<UiClickEvent>, mut commands: Commands, query: Query<(&OnUiClickDespawn, Entity)>) {
     for event in events.read() {
         if let Ok((listener, entity)) = query.get(event.target) {
-            commands.entity(if let Some(e) = listener.target { e } else { entity }).despawn_recursive();
+            let mut target = commands.entity(if let Some(e) = listener.target { e } else { entity });
+            if listener.outro { target.despawn_with_outro(); } else { target.despawn_recursive(); }
         }
     }
 }
@@ -90,8 +90,17 @@ pub enum GamepadCursorMode {
     /// Cursor will freely move on input.
     #[default]
     Free,
-    // /// Will try to snap to nearby nodes on input.
-    //Snap,
+    /// Cursor will snap to the center of the closest pickable node in the direction of the stick.
+    Snap {
+        /// Distance in pixels from a node center at which the cursor is pulled onto it, `0.0` disables it.
+        magnetism: f32,
+    },
+}
+impl GamepadCursorMode {
+    /// Creates new snapping mode without magnetism.
+    pub fn snap() -> Self {
+        GamepadCursorMode::Snap { magnetism: 0.0 }
+    }
 }
 
 
@@ -193,6 +202,8 @@ fn gamepad_move_cursor(
 ) {
     if let Ok(window) = windows.get_single() {
         for (mut cursor, gamepad) in query.iter_mut() {
+            if gamepad.mode != GamepadCursorMode::Free { continue; }
+
             // Pull axis values
             let x = axis.get(GamepadAxis { gamepad: Gamepad::new(gamepad.id), axis_type: GamepadAxisType::LeftStickX });
             let y = axis.get(GamepadAxis { gamepad: Gamepad::new(gamepad.id), axis_type: GamepadAxisType::LeftStickY });
@@ -212,6 +223,63 @@ fn gamepad_move_cursor(
     }
 }
 
+/// This function snaps the cursor onto pickable nodes based on gamepad input
+fn gamepad_snap_cursor(
+    axis: Res<Axis<GamepadAxis>>,
+    time: Res<Time>,
+    mut neutral: Local<HashMap<Entity, bool>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    nodes: Query<(&Dimension, &GlobalTransform, Has<Element>, Option<&Pickable>, &ViewVisibility)>,
+    mut query: Query<(Entity, &mut Cursor2d, &GamepadCursor, Option<&Parent>)>,
+) {
+    let Ok(window) = windows.get_single() else { return };
+    for (entity, mut cursor, gamepad, parent_option) in query.iter_mut() {
+        let GamepadCursorMode::Snap { magnetism } = gamepad.mode else { continue; };
+
+        // Use the parent camera or the first active one
+        let camera = parent_option.and_then(|parent| cameras.get(**parent).ok()).or_else(|| cameras.iter().find(|(_, camera, _)| camera.is_active));
+        let Some((_, camera, camera_transform)) = camera else { continue; };
+
+        // Centers of all hoverable nodes in cursor coordinates, nodes without Pickable are hoverable by default
+        let centers: Vec<Vec2> = nodes.iter().filter(|(.., pickable, visibility)| pickable.map(|pickable| pickable.is_hoverable).unwrap_or(true) && visibility.get()).filter_map(|(dimension, transform, element, ..)| {
+            let position = camera.world_to_viewport(camera_transform, picking::node_center(dimension, transform, element))?;
+            Some(Vec2::new(position.x - window.width()/2.0, window.height()/2.0 - position.y))
+        }).collect();
+
+        // Pull axis values
+        let x = axis.get(GamepadAxis { gamepad: Gamepad::new(gamepad.id), axis_type: GamepadAxisType::LeftStickX }).unwrap_or(0.0);
+        let y = axis.get(GamepadAxis { gamepad: Gamepad::new(gamepad.id), axis_type: GamepadAxisType::LeftStickY }).unwrap_or(0.0);
+        let stick = Vec2::new(x, y);
+
+        // Snap once per stick flick
+        let was_neutral = neutral.get(&entity).copied().unwrap_or(true);
+        neutral.insert(entity, stick.length() < 0.5);
+        if stick.length() >= 0.5 {
+            if !was_neutral { continue; }
+            let direction = stick.normalize();
+            let nearest = centers.iter().filter_map(|center| {
+                let offset = *center - cursor.location;
+                let along = offset.dot(direction);
+                if along <= 1.0 { return None }
+                // Prefer nodes aligned with the direction over closer ones off to the side
+                Some((*center, along + offset.perp_dot(direction).abs() * 2.0))
+            }).min_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((center, _)) = nearest { cursor.location = center; }
+            continue;
+        }
+
+        // Pull the drifting cursor onto a close node
+        if magnetism > 0.0 {
+            let location = cursor.location;
+            let closest = centers.iter().map(|center| (*center, center.distance(location))).filter(|(_, distance)| *distance <= magnetism && *distance > 0.0).min_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((center, _)) = closest {
+                cursor.location = location.lerp(center, (time.delta_seconds() * 10.0 * gamepad.speed).min(1.0));
+            }
+        }
+    }
+}
+
 /// This function controls the location of the cursor based on mouse input
 fn mouse_move_cursor(
     windows: Query<&Window, With<PrimaryWindow>>,
@@ -433,7 +501,7 @@ impl Plugin for CursorPlugin {
 
             // Add core systems 
             .add_systems(PreUpdate, cursor_reset_icon)
-            .add_systems(PreUpdate, (gamepad_move_cursor, mouse_move_cursor, cursor_update_transform, cursor_move_virtual_pointer).chain())
+            .add_systems(PreUpdate, (gamepad_move_cursor, gamepad_snap_cursor, mouse_move_cursor, cursor_update_transform, cursor_move_virtual_pointer).chain())
             .add_systems(PostUpdate, cursor_set_visibility)
             .add_systems(PostUpdate, cursor_change_native)
             .add_systems(PostUpdate, cursor_update_texture)
@@ -0,0 +1,397 @@
+use bevy::{ecs::system::EntityCommands, utils::HashMap, window::PrimaryWindow};
+
+use crate::*;
+
+
+// #==============#
+// #=== EVENTS ===#
+
+/// This event is sent when a dragged [`UiDraggable`] enters a [`UiDropTarget`] accepting its payload.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct UiDragEnter<P> {
+    /// The dragged entity
+    pub source: Entity,
+    /// The drop target entity
+    pub target: Entity,
+    marker: PhantomData<P>,
+}
+
+/// This event is sent when a dragged [`UiDraggable`] leaves a [`UiDropTarget`] or is dropped on it.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct UiDragLeave<P> {
+    /// The dragged entity
+    pub source: Entity,
+    /// The drop target entity
+    pub target: Entity,
+    marker: PhantomData<P>,
+}
+
+/// This event is sent when [`UiDraggable`] is dropped on [`UiDropTarget`] accepting its payload.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct UiDropEvent<P> {
+    /// The dragged entity
+    pub source: Entity,
+    /// The drop target entity
+    pub target: Entity,
+    /// The payload of the dragged entity
+    pub payload: P,
+}
+
+
+// #=========================#
+// #=== DRAG DROP STRUCTS ===#
+
+/// **Ui draggable** - Component making the entity draggable with a payload. While dragged, a ghost entity follows the pointer.
+/// If the entity has [`Sprite`], the ghost is a faded copy of it, otherwise use [`UiDraggable::ghost`] to build it.
+/// Dropping it outside of an accepting [`UiDropTarget`] moves the ghost back. Requires [`UiDragDropPlugin`] for the payload type.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Clone)]
+/// struct Item(u32);
+///
+/// let draggable = UiDraggable::new(Item(7));
+/// let slot = UiDropTarget::<Item>::new().accept(|item| item.0 != 0);
+/// ```
+#[allow(unpredictable_function_pointer_comparisons)]
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiDraggable<P> {
+    /// The payload sent with the drop
+    pub payload: P,
+    /// Function filling the ghost entity, it is spawned with a transform following the pointer
+    pub ghost: Option<fn(&mut EntityCommands)>,
+}
+impl <P> UiDraggable<P> {
+    /// Creates new draggable with the payload.
+    pub fn new(payload: P) -> Self {
+        UiDraggable { payload, ghost: None }
+    }
+    /// Replaces the ghost builder with a new value.
+    pub fn ghost(mut self, ghost: fn(&mut EntityCommands)) -> Self {
+        self.ghost = Some(ghost);
+        self
+    }
+}
+
+/// **Ui drop target** - Component making the entity accept drops of [`UiDraggable`] with the same payload type.
+#[allow(unpredictable_function_pointer_comparisons)]
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiDropTarget<P> {
+    /// Function deciding if the payload is accepted, all payloads are accepted without it
+    pub accept: Option<fn(&P) -> bool>,
+}
+impl <P> UiDropTarget<P> {
+    /// Creates new drop target accepting all payloads.
+    pub fn new() -> Self {
+        UiDropTarget { accept: None }
+    }
+    /// Replaces the accept function with a new value.
+    pub fn accept(mut self, accept: fn(&P) -> bool) -> Self {
+        self.accept = Some(accept);
+        self
+    }
+    /// Checks if the payload is accepted.
+    pub fn accepts(&self, payload: &P) -> bool {
+        self.accept.map(|accept| accept(payload)).unwrap_or(true)
+    }
+}
+impl <P> Default for UiDropTarget<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// This struct marks the ghost entity following the pointer while dragging.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiDragGhost {
+    /// The dragged entity
+    pub source: Entity,
+    /// Position the ghost returns from after a rejected drop
+    pub (crate) from: Vec3,
+    /// Progress of the return, `None` while dragging
+    pub (crate) returning: Option<f32>,
+}
+impl UiDragGhost {
+    /// Advances the return by the time and returns the position between the drop position and the node. Returns `None` while dragging.
+    fn return_to(&mut self, delta: f32, to: Vec3) -> Option<Vec3> {
+        let progress = (self.returning? + delta / RETURN_DURATION).min(1.0);
+        self.returning = Some(progress);
+        Some(self.from.lerp(to, UiEase::Cubic(EaseMode::Out).ease(progress)))
+    }
+    /// Checks if the ghost returned to the node.
+    fn is_returned(&self) -> bool {
+        self.returning.map(|progress| progress >= 1.0).unwrap_or(false)
+    }
+}
+
+/// A single drag in progress.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    source: Entity,
+    ghost: Entity,
+    /// Offset from the pointer to the center of the dragged node
+    offset: Vec3,
+    /// The accepting drop target under the pointer
+    hovered: Option<Entity>,
+}
+impl Drag {
+    /// Moves the drag over the drop target and returns the changes in the order their events are sent.
+    fn hover(&mut self, hovered: Option<Entity>) -> Vec<DragChange> {
+        if hovered == self.hovered { return Vec::new() }
+        let changes = self.hovered.map(DragChange::Leave).into_iter().chain(hovered.map(DragChange::Enter)).collect();
+        self.hovered = hovered;
+        changes
+    }
+    /// Ends the drag and returns the changes in the order their events are sent. Without changes the ghost returns.
+    fn end(&self) -> Vec<DragChange> {
+        self.hovered.map(|target| vec![DragChange::Leave(target), DragChange::Drop(target)]).unwrap_or_default()
+    }
+}
+
+/// Change of the drop target of a drag, each is sent as its event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DragChange {
+    Enter(Entity),
+    Leave(Entity),
+    Drop(Entity),
+}
+
+
+// #=======================#
+// #=== DRAG DROP LOGIC ===#
+
+/// How long the ghost takes to return after a rejected drop.
+const RETURN_DURATION: f32 = 0.25;
+
+/// Ghosts are placed this far in front of the dragged node.
+const GHOST_DEPTH: f32 = 100.0;
+
+/// Returns the closest hit drop target accepting the payload, the dragged entity itself is skipped.
+fn closest_accepting<'a, P: 'a>(hits: impl IntoIterator<Item = (Entity, &'a UiDropTarget<P>, f32)>, source: Entity, payload: &P) -> Option<Entity> {
+    hits.into_iter()
+        .filter(|(entity, target, _)| *entity != source && target.accepts(payload))
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+        .map(|(entity, ..)| entity)
+}
+
+/// This system starts, moves and ends drags of [`UiDraggable`] entities with the payload `P`.
+fn ui_drag_drop_system<P: Clone + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut drags: Local<HashMap<PointerId, Drag>>,
+    mut start: EventReader<Pointer<DragStart>>,
+    mut drag: EventReader<Pointer<bevy_mod_picking::events::Drag>>,
+    mut end: EventReader<Pointer<DragEnd>>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    sources: Query<(&UiDraggable<P>, &Dimension, Has<Element>, &GlobalTransform, Option<&Sprite>, Option<&Handle<Image>>)>,
+    targets: Query<(Entity, &UiDropTarget<P>, &Dimension, Has<Element>, &GlobalTransform, Option<&UiClip>, &ViewVisibility)>,
+    mut ghosts: Query<(&mut Transform, &mut UiDragGhost)>,
+    mut enter: EventWriter<UiDragEnter<P>>,
+    mut leave: EventWriter<UiDragLeave<P>>,
+    mut drop: EventWriter<UiDropEvent<P>>,
+) {
+    // Returns the world position under the pointer in the plane of the node
+    let pointer_position = |location: &bevy_mod_picking::pointer::Location, transform: &GlobalTransform| {
+        let (_, _, ray) = picking::pointer_ray(location, &cameras, &primary_window)?;
+        picking::ray_hit_plane(ray, transform).map(|local| transform.transform_point(local))
+    };
+
+    // Returns the closest accepting drop target under the pointer
+    let hovered_target = |location: &bevy_mod_picking::pointer::Location, source: Entity, payload: &P| {
+        let (_, _, ray) = picking::pointer_ray(location, &cameras, &primary_window)?;
+        let hits = targets.iter().filter(|(.., visibility)| visibility.get()).filter_map(|(entity, target, dimension, element, transform, clip, _)| {
+            let position = picking::ray_hit_node(ray, dimension, element, transform, clip)?;
+            Some((entity, target, ray.origin.distance(position)))
+        });
+        closest_accepting(hits, source, payload)
+    };
+
+    // Sends the event of the change
+    let mut send = |change: DragChange, source: Entity, payload: &P| match change {
+        DragChange::Enter(target) => { enter.send(UiDragEnter { source, target, marker: PhantomData }); },
+        DragChange::Leave(target) => { leave.send(UiDragLeave { source, target, marker: PhantomData }); },
+        DragChange::Drop(target) => { drop.send(UiDropEvent { source, target, payload: payload.clone() }); },
+    };
+
+    // Spawn the ghost
+    for event in start.read() {
+        let Ok((draggable, dimension, element, transform, sprite, image)) = sources.get(event.target) else { continue };
+        let center = picking::node_center(dimension, transform, element);
+        let offset = pointer_position(&event.pointer_location, transform).map(|position| center - position).unwrap_or(Vec3::ZERO);
+
+        let mut ghost = commands.spawn((
+            SpatialBundle::from_transform(Transform::from_translation(center + Vec3::Z * GHOST_DEPTH).with_rotation(transform.compute_transform().rotation)),
+            UiDragGhost { source: event.target, from: center, returning: None },
+        ));
+        match (draggable.ghost, sprite) {
+            (Some(builder), _) => builder(&mut ghost),
+            (None, Some(sprite)) => {
+                let color = sprite.color.with_alpha(sprite.color.alpha() * 0.6);
+                ghost.insert((Sprite { color, custom_size: Some(dimension.size), anchor: bevy::sprite::Anchor::Center, ..sprite.clone() }, image.cloned().unwrap_or_default()));
+            },
+            _ => {},
+        }
+        drags.insert(event.pointer_id, Drag { source: event.target, ghost: ghost.id(), offset, hovered: None });
+    }
+
+    // Move the ghost and track the drop target under the pointer
+    for event in drag.read() {
+        let Some(state) = drags.get_mut(&event.pointer_id) else { continue };
+        let Ok((draggable, .., transform, _, _)) = sources.get(state.source) else { continue };
+        if let (Some(position), Ok((mut ghost_transform, _))) = (pointer_position(&event.pointer_location, transform), ghosts.get_mut(state.ghost)) {
+            ghost_transform.translation = position + state.offset + Vec3::Z * GHOST_DEPTH;
+        }
+        let hovered = hovered_target(&event.pointer_location, state.source, &draggable.payload);
+        for change in state.hover(hovered) {
+            send(change, state.source, &draggable.payload);
+        }
+    }
+
+    // Drop or return the ghost
+    for event in end.read() {
+        let Some(state) = drags.remove(&event.pointer_id) else { continue };
+        let Ok((draggable, ..)) = sources.get(state.source) else {
+            commands.entity(state.ghost).despawn_recursive();
+            continue;
+        };
+        let changes = state.end();
+        if changes.is_empty() {
+            if let Ok((transform, mut ghost)) = ghosts.get_mut(state.ghost) {
+                // Return from the current position to the dragged node
+                ghost.from = transform.translation;
+                ghost.returning = Some(0.0);
+            }
+            continue;
+        }
+        for change in changes {
+            send(change, state.source, &draggable.payload);
+        }
+        commands.entity(state.ghost).despawn_recursive();
+    }
+}
+
+/// This system marks new draggables with [`UiDragCapture`], so dragging them does not scroll [`UiScroll`] containers.
+fn ui_drag_capture_draggable<P: Clone + Send + Sync + 'static>(mut commands: Commands, query: Query<Entity, (Added<UiDraggable<P>>, Without<UiDragCapture>)>) {
+    for entity in &query {
+        commands.entity(entity).insert(UiDragCapture);
+    }
+}
+
+/// This system moves the ghosts of rejected drops back to their dragged nodes and despawns them.
+fn ui_drag_ghost_return(
+    time: Res<Time>,
+    mut commands: Commands,
+    sources: Query<(&Dimension, Has<Element>, &GlobalTransform)>,
+    mut query: Query<(Entity, &mut Transform, &mut UiDragGhost)>,
+) {
+    for (entity, mut transform, mut ghost) in &mut query {
+        if ghost.returning.is_none() { continue }
+        let Ok((dimension, element, source_transform)) = sources.get(ghost.source) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        let to = picking::node_center(dimension, source_transform, element) + Vec3::Z * GHOST_DEPTH;
+        if let Some(position) = ghost.return_to(time.delta_seconds(), to) { transform.translation = position; }
+        if ghost.is_returned() { commands.entity(entity).despawn_recursive(); }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding drag and drop for the payload type `P`, see [`UiDraggable`] and [`UiDropTarget`].
+#[derive(Debug, Default, Clone)]
+pub struct UiDragDropPlugin <P: Clone + Send + Sync + 'static>(PhantomData<P>);
+impl <P: Clone + Send + Sync + 'static> UiDragDropPlugin<P> {
+    pub fn new() -> Self {
+        UiDragDropPlugin::<P>(PhantomData)
+    }
+}
+impl <P: Clone + Send + Sync + 'static> Plugin for UiDragDropPlugin<P> {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<UiDragEnter<P>>()
+            .add_event::<UiDragLeave<P>>()
+            .add_event::<UiDropEvent<P>>()
+            .add_systems(Update, (ui_drag_capture_draggable::<P>, ui_drag_drop_system::<P>.before(ui_drag_ghost_return)));
+
+        // The return animation is shared by all payload types
+        if !app.is_plugin_added::<DragGhostPlugin>() {
+            app.add_plugins(DragGhostPlugin);
+        }
+    }
+}
+
+/// Plugin returning the ghosts of rejected drops.
+struct DragGhostPlugin;
+impl Plugin for DragGhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, ui_drag_ghost_return);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use bevy::prelude::{Entity, Vec3};
+    use super::{closest_accepting, Drag, DragChange, UiDragGhost, UiDropTarget, RETURN_DURATION};
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_raw(index)
+    }
+
+    fn drag() -> Drag {
+        Drag { source: entity(0), ghost: entity(1), offset: Vec3::ZERO, hovered: None }
+    }
+
+    #[test]
+    fn hover_order() {
+        let mut drag = drag();
+        assert_eq!(drag.hover(Some(entity(2))), [DragChange::Enter(entity(2))]);
+        assert!(drag.hover(Some(entity(2))).is_empty());
+
+        // Leaves the previous target before entering the next one
+        assert_eq!(drag.hover(Some(entity(3))), [DragChange::Leave(entity(2)), DragChange::Enter(entity(3))]);
+        assert_eq!(drag.hover(None), [DragChange::Leave(entity(3))]);
+        assert!(drag.end().is_empty());
+    }
+
+    #[test]
+    fn drop_order() {
+        let mut drag = drag();
+        drag.hover(Some(entity(2)));
+        assert_eq!(drag.end(), [DragChange::Leave(entity(2)), DragChange::Drop(entity(2))]);
+    }
+
+    #[test]
+    fn rejected() {
+        let odd = UiDropTarget::<u32>::new().accept(|payload| *payload % 2 == 1);
+        let any = UiDropTarget::<u32>::new();
+
+        // The closer target rejecting the payload is skipped
+        let hits = [(entity(2), &odd, 1.0), (entity(3), &any, 2.0)];
+        assert_eq!(closest_accepting(hits, entity(0), &4), Some(entity(3)));
+        assert_eq!(closest_accepting(hits, entity(0), &5), Some(entity(2)));
+
+        // Nothing accepts it, so the drag ends without a drop and the ghost returns
+        assert_eq!(closest_accepting([(entity(2), &odd, 1.0)], entity(0), &4), None);
+
+        // The dragged entity is never its own target
+        assert_eq!(closest_accepting([(entity(0), &any, 0.5)], entity(0), &4), None);
+    }
+
+    #[test]
+    fn ghost_return() {
+        let mut ghost = UiDragGhost { source: entity(0), from: Vec3::new(100.0, 0.0, 0.0), returning: None };
+        assert_eq!(ghost.return_to(0.1, Vec3::ZERO), None);
+
+        ghost.returning = Some(0.0);
+        let position = ghost.return_to(RETURN_DURATION / 2.0, Vec3::ZERO).unwrap();
+        assert!(position.x > 0.0 && position.x < 50.0, "Eased out return is past the half at {position}");
+        assert!(!ghost.is_returned());
+
+        assert_eq!(ghost.return_to(RETURN_DURATION, Vec3::ZERO), Some(Vec3::ZERO));
+        assert!(ghost.is_returned());
+    }
+}
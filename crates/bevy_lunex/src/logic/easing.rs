@@ -0,0 +1,249 @@
+use std::f32::consts::PI;
+
+
+// #====================#
+// #=== EASING CURVE ===#
+
+/// Which end of the curve the easing is applied to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EaseMode {
+    /// The curve starts slow and speeds up.
+    In,
+    /// The curve starts fast and slows down.
+    #[default]
+    Out,
+    /// The curve starts and ends slow.
+    InOut,
+}
+
+/// **Ui ease** - An easing curve that maps the linear progress ranging from `0.0` to `1.0` onto the animated value.
+/// Some curves like [`UiEase::Back`], [`UiEase::Elastic`] or [`UiEase::Spring`] overshoot outside of that range.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let ease = UiEase::Cubic(EaseMode::InOut);
+/// let ease = UiEase::bezier(0.25, 0.1, 0.25, 1.0);
+/// let ease = UiEase::spring(170.0, 26.0);
+/// ```
+#[allow(unpredictable_function_pointer_comparisons)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum UiEase {
+    /// No easing, the value equals the progress.
+    #[default]
+    Linear,
+    /// Quadratic curve.
+    Quad(EaseMode),
+    /// Cubic curve.
+    Cubic(EaseMode),
+    /// Exponential curve.
+    Expo(EaseMode),
+    /// Curve that slightly overshoots before settling.
+    Back(EaseMode),
+    /// Curve that oscillates before settling.
+    Elastic(EaseMode),
+    /// Curve that bounces before settling.
+    Bounce(EaseMode),
+    /// Cubic bezier with the control points `(x1, y1)` and `(x2, y2)`, same as `cubic-bezier` in CSS.
+    Bezier(f32, f32, f32, f32),
+    /// Damped spring with unit mass. It takes [`UiEase::duration`] seconds to settle.
+    Spring {
+        /// How strongly the spring pulls towards the target.
+        stiffness: f32,
+        /// How strongly the motion is damped.
+        damping: f32,
+    },
+    /// Custom curve function.
+    Custom(fn(f32) -> f32),
+}
+impl UiEase {
+    /// Creates new cubic bezier curve, same as `cubic-bezier` in CSS.
+    pub fn bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        UiEase::Bezier(x1, y1, x2, y2)
+    }
+    /// Creates new spring curve.
+    pub fn spring(stiffness: f32, damping: f32) -> Self {
+        UiEase::Spring { stiffness, damping }
+    }
+
+    /// Returns the time in seconds the curve takes to settle. Only springs have their own duration.
+    pub fn duration(&self) -> Option<f32> {
+        match self {
+            UiEase::Spring { stiffness, damping } => Some(10000f32.ln() / spring_decay(*stiffness, *damping)),
+            _ => None,
+        }
+    }
+
+    /// Maps the progress onto the value of the curve.
+    pub fn ease(&self, t: f32) -> f32 {
+        if t <= 0.0 { return 0.0 }
+        if t >= 1.0 { return 1.0 }
+        match self {
+            UiEase::Linear => t,
+            UiEase::Quad(mode) => mode.apply(t, |t| t * t),
+            UiEase::Cubic(mode) => mode.apply(t, |t| t * t * t),
+            UiEase::Expo(mode) => mode.apply(t, |t| 2f32.powf(10.0 * t - 10.0)),
+            UiEase::Back(mode) => mode.apply(t, |t| 2.70158 * t * t * t - 1.70158 * t * t),
+            UiEase::Elastic(mode) => mode.apply(t, |t| -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * (2.0 * PI) / 3.0).sin()),
+            UiEase::Bounce(mode) => mode.apply(t, |t| 1.0 - bounce_out(1.0 - t)),
+            UiEase::Bezier(x1, y1, x2, y2) => {
+                let s = solve(t, |s| bezier(s, *x1, *x2));
+                bezier(s, *y1, *y2)
+            },
+            UiEase::Spring { stiffness, damping } => spring(t * self.duration().unwrap_or(1.0), *stiffness, *damping),
+            UiEase::Custom(method) => method(t),
+        }
+    }
+
+    /// Returns if the curve never goes back, so every value is reached at a single progress.
+    /// Curves that overshoot or oscillate are not monotonic, [`UiEase::Custom`] curves are assumed not to be.
+    pub fn is_monotonic(&self) -> bool {
+        match self {
+            UiEase::Linear | UiEase::Quad(_) | UiEase::Cubic(_) | UiEase::Expo(_) => true,
+            UiEase::Back(_) | UiEase::Elastic(_) | UiEase::Bounce(_) | UiEase::Custom(_) => false,
+            UiEase::Bezier(_, y1, _, y2) => (0.0..=1.0).contains(y1) && (0.0..=1.0).contains(y2),
+            UiEase::Spring { stiffness, damping } => *damping >= 2.0 * stiffness.max(f32::EPSILON).sqrt(),
+        }
+    }
+
+    /// Returns the progress at which the curve reaches the value. Returns `None` if the curve is not monotonic.
+    pub fn progress(&self, value: f32) -> Option<f32> {
+        if !self.is_monotonic() { return None }
+        Some(solve(value, |t| self.ease(t)))
+    }
+}
+
+impl EaseMode {
+    /// Applies the mode to the easing in curve.
+    fn apply(&self, t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+        match self {
+            EaseMode::In => ease_in(t),
+            EaseMode::Out => 1.0 - ease_in(1.0 - t),
+            EaseMode::InOut => if t < 0.5 { ease_in(2.0 * t) / 2.0 } else { 1.0 - ease_in(2.0 - 2.0 * t) / 2.0 },
+        }
+    }
+}
+
+/// Bouncing curve ending slow.
+fn bounce_out(t: f32) -> f32 {
+    let (n, d) = (7.5625, 2.75);
+    if t < 1.0 / d {
+        n * t * t
+    } else if t < 2.0 / d {
+        let t = t - 1.5 / d;
+        n * t * t + 0.75
+    } else if t < 2.5 / d {
+        let t = t - 2.25 / d;
+        n * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d;
+        n * t * t + 0.984375
+    }
+}
+
+/// One axis of cubic bezier going from `0.0` to `1.0`.
+fn bezier(s: f32, p1: f32, p2: f32) -> f32 {
+    let r = 1.0 - s;
+    3.0 * r * r * s * p1 + 3.0 * r * s * s * p2 + s * s * s
+}
+
+/// Finds the input in range from `0.0` to `1.0` at which the rising function reaches the value.
+fn solve(value: f32, function: impl Fn(f32) -> f32) -> f32 {
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..32 {
+        let mid = (low + high) / 2.0;
+        if function(mid) < value { low = mid } else { high = mid }
+    }
+    (low + high) / 2.0
+}
+
+/// The rate at which the slowest part of the spring motion decays.
+fn spring_decay(stiffness: f32, damping: f32) -> f32 {
+    let omega = stiffness.max(f32::EPSILON).sqrt();
+    let zeta = damping.max(f32::EPSILON) / (2.0 * omega);
+    if zeta <= 1.0 { zeta * omega } else { omega * (zeta - (zeta * zeta - 1.0).sqrt()) }
+}
+
+/// Position of the damped spring released at `0.0` with the target at `1.0` after `t` seconds.
+fn spring(t: f32, stiffness: f32, damping: f32) -> f32 {
+    let omega = stiffness.max(f32::EPSILON).sqrt();
+    let zeta = damping.max(f32::EPSILON) / (2.0 * omega);
+    if zeta < 1.0 {
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        1.0 - (-zeta * omega * t).exp() * ((omega_d * t).cos() + zeta * omega / omega_d * (omega_d * t).sin())
+    } else if zeta == 1.0 {
+        1.0 - (-omega * t).exp() * (1.0 + omega * t)
+    } else {
+        let root = (zeta * zeta - 1.0).sqrt();
+        let (r1, r2) = (-omega * (zeta - root), -omega * (zeta + root));
+        1.0 - (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r2 - r1)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{UiEase, EaseMode};
+
+    const CURVES: [UiEase; 10] = [
+        UiEase::Linear,
+        UiEase::Quad(EaseMode::In),
+        UiEase::Cubic(EaseMode::InOut),
+        UiEase::Expo(EaseMode::Out),
+        UiEase::Back(EaseMode::Out),
+        UiEase::Elastic(EaseMode::Out),
+        UiEase::Bounce(EaseMode::Out),
+        UiEase::Bezier(0.25, 0.1, 0.25, 1.0),
+        UiEase::Spring { stiffness: 170.0, damping: 26.0 },
+        UiEase::Spring { stiffness: 100.0, damping: 20.0 },
+    ];
+
+    fn approx(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn ends() {
+        for ease in CURVES {
+            assert_eq!(ease.ease(0.0), 0.0, "{ease:?}");
+            assert_eq!(ease.ease(1.0), 1.0, "{ease:?}");
+        }
+    }
+
+    #[test]
+    fn modes() {
+        assert!(approx(UiEase::Quad(EaseMode::In).ease(0.5), 0.25));
+        assert!(approx(UiEase::Quad(EaseMode::Out).ease(0.5), 0.75));
+        assert!(approx(UiEase::Cubic(EaseMode::InOut).ease(0.5), 0.5));
+        assert!(approx(UiEase::Cubic(EaseMode::InOut).ease(0.25), 1.0 - UiEase::Cubic(EaseMode::InOut).ease(0.75)));
+        assert!(approx(UiEase::bezier(0.0, 0.0, 1.0, 1.0).ease(0.3), 0.3));
+    }
+
+    #[test]
+    fn overshoot() {
+        assert!((0..100).any(|i| UiEase::Back(EaseMode::Out).ease(i as f32 / 100.0) > 1.0));
+        assert!((0..100).any(|i| UiEase::spring(170.0, 10.0).ease(i as f32 / 100.0) > 1.0));
+        assert!((0..100).all(|i| UiEase::spring(100.0, 20.0).ease(i as f32 / 100.0) <= 1.0));
+    }
+
+    #[test]
+    fn progress() {
+        for ease in CURVES {
+            match ease.progress(0.5) {
+                Some(t) => assert!(approx(ease.ease(t), 0.5), "{ease:?}"),
+                None => assert!(!ease.is_monotonic(), "{ease:?}"),
+            }
+        }
+        assert_eq!(UiEase::Elastic(EaseMode::Out).progress(0.5), None);
+        assert_eq!(UiEase::spring(170.0, 10.0).progress(0.5), None);
+        assert!(UiEase::spring(100.0, 20.0).progress(0.5).is_some());
+        assert_eq!(UiEase::bezier(0.3, -0.5, 0.7, 1.5).progress(0.5), None);
+    }
+
+    #[test]
+    fn spring_duration() {
+        let ease = UiEase::spring(170.0, 26.0);
+        assert!(ease.duration().unwrap() > 0.0);
+        assert!(approx(ease.ease(0.999), 1.0));
+        assert_eq!(UiEase::Linear.duration(), None);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::*;
+
+
+// #=====================#
+// #=== FOCUS STRUCTS ===#
+
+/// Resource holding the entity that has keyboard and gamepad focus.
+/// The focused entity animates its [`UiAnimator<Selected>`] forward, which can be used to draw focus rings.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UiFocus {
+    /// The focused entity
+    pub focused: Option<Entity>,
+}
+
+/// This struct marks entity as focusable by keyboard and gamepad navigation.
+/// * `Tab` and `Shift+Tab` or gamepad shoulder buttons move the focus in tab order
+/// * Arrow keys or gamepad d-pad move the focus to the nearest node in that direction
+/// * `Enter` or gamepad `A` button sends [`UiClickEvent`] for the focused entity
+/// * While [`UiModal`] is open, only its subnodes can be focused
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let focusable = UiFocusable::new().order(1);
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct UiFocusable {
+    /// Explicit tab order. Nodes with it come first in ascending order, the rest follows in [`UiTree`] order
+    pub order: Option<i32>,
+    /// The [`UiTree`] entity of the node
+    pub (crate) tree: Option<Entity>,
+    /// Position of the node in [`UiTree`]
+    pub (crate) tree_order: usize,
+}
+impl UiFocusable {
+    /// Creates new struct
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Replaces the tab order with a new value.
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+    /// Sets the tab order to a new value.
+    pub fn set_order(&mut self, order: i32) {
+        self.order = Some(order);
+    }
+    /// Returns the key sorting the nodes in tab order. Nodes of one tree stay together.
+    fn tab_key(&self) -> (bool, Option<i32>, Option<Entity>, usize) {
+        (self.order.is_none(), self.order, self.tree, self.tree_order)
+    }
+}
+
+
+// #===================#
+// #=== FOCUS LOGIC ===#
+
+/// This system derives the tab order of focusable entities from the order of their nodes in [`UiTree`].
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn ui_focus_tree_order<T:Component, N:Default + Component>(
+    uis: Query<(Entity, &UiTree<T, N>, &Children), Changed<UiTree<T, N>>>,
+    mut query: Query<(&UiLink<T>, &mut UiFocusable)>,
+) {
+    for (tree, ui, children) in &uis {
+        let order: bevy::utils::HashMap<&String, usize> = ui.crawl().into_iter().enumerate().map(|(i, node)| (node.get_path(), i)).collect();
+        for child in children {
+            if let Ok((link, mut focusable)) = query.get_mut(*child) {
+                let index = order.get(&link.path).copied().unwrap_or(usize::MAX);
+                if focusable.tree != Some(tree) || focusable.tree_order != index {
+                    focusable.tree = Some(tree);
+                    focusable.tree_order = index;
+                }
+            }
+        }
+    }
+}
+
+/// This system moves the focus on keyboard and gamepad input and activates the focused entity.
+pub (crate) fn ui_focus_navigation(
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+    mut focus: ResMut<UiFocus>,
+    query: Query<(Entity, &UiFocusable, &Dimension, &GlobalTransform, Has<Element>, &ViewVisibility)>,
+    inputs: Query<(), With<UiTextInput>>,
+    modals: Res<UiModalStack>,
+    parts: Query<&UiModalPart>,
+    mut click: EventWriter<UiClickEvent>,
+) {
+    let button = |button: GamepadButtonType| gamepads.iter().any(|gamepad| buttons.just_pressed(GamepadButton::new(gamepad, button)));
+    let shift = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+
+    // Focused text inputs use arrow keys and Enter for editing, Tab still moves the focus
+    let typing = focus.focused.map(|focused| inputs.contains(focused)).unwrap_or(false);
+    let pressed = |key: KeyCode, gamepad: GamepadButtonType| (keys.just_pressed(key) && !typing) || button(gamepad);
+
+    // Forget despawned or hidden entities and the ones outside of the top modal
+    let mut nodes: Vec<_> = query.iter().filter(|(.., visibility)| visibility.get()).filter(|(e, ..)| {
+        modals.top().map(|modal| parts.get(*e).map(|part| part.modal == modal).unwrap_or(false)).unwrap_or(true)
+    }).collect();
+    if let Some(focused) = focus.focused {
+        if !nodes.iter().any(|(e, ..)| *e == focused) { focus.focused = None; }
+    }
+
+    // Activate the focused entity
+    if pressed(KeyCode::Enter, GamepadButtonType::South) {
+        if let Some(focused) = focus.focused {
+            click.send(UiClickEvent { target: focused });
+        }
+    }
+
+    // Move in tab order
+    let tab = keys.just_pressed(KeyCode::Tab);
+    let next = (tab && !shift) || button(GamepadButtonType::RightTrigger);
+    let previous = (tab && shift) || button(GamepadButtonType::LeftTrigger);
+    if (next || previous) && !nodes.is_empty() {
+        nodes.sort_by_key(|(_, focusable, ..)| focusable.tab_key());
+        let index = focus.focused.and_then(|focused| nodes.iter().position(|(e, ..)| *e == focused));
+        let index = match (index, next) {
+            (None, true) => 0,
+            (None, false) => nodes.len() - 1,
+            (Some(i), true) => (i + 1) % nodes.len(),
+            (Some(i), false) => (i + nodes.len() - 1) % nodes.len(),
+        };
+        focus.focused = Some(nodes[index].0);
+        return;
+    }
+
+    // Move to the nearest node in direction
+    let direction = if pressed(KeyCode::ArrowUp, GamepadButtonType::DPadUp) { Vec2::Y }
+        else if pressed(KeyCode::ArrowDown, GamepadButtonType::DPadDown) { Vec2::NEG_Y }
+        else if pressed(KeyCode::ArrowLeft, GamepadButtonType::DPadLeft) { Vec2::NEG_X }
+        else if pressed(KeyCode::ArrowRight, GamepadButtonType::DPadRight) { Vec2::X }
+        else { return };
+
+    let Some(current) = focus.focused.and_then(|focused| nodes.iter().find(|(e, ..)| *e == focused)) else {
+        // Nothing is focused, start with the first node in tab order
+        focus.focused = nodes.iter().min_by_key(|(_, focusable, ..)| focusable.tab_key()).map(|(e, ..)| *e);
+        return;
+    };
+    let origin = picking::node_center(current.2, current.3, current.4).truncate();
+    let nearest = nodes.iter().filter(|(e, ..)| *e != current.0).filter_map(|(e, _, dimension, transform, element, _)| {
+        let offset = picking::node_center(dimension, transform, *element).truncate() - origin;
+        let along = offset.dot(direction);
+        if along <= 0.0 { return None }
+        // Prefer nodes aligned with the direction over closer ones off to the side
+        let across = offset.perp_dot(direction).abs();
+        Some((*e, along + across * 2.0))
+    }).min_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((entity, _)) = nearest {
+        focus.focused = Some(entity);
+    }
+}
+
+/// This system animates the [`UiAnimator<Selected>`] of the focused entity forward and of the unfocused one backward.
+fn ui_focus_animation(focus: Res<UiFocus>, mut last: Local<Option<Entity>>, mut query: Query<&mut UiAnimator<Selected>>) {
+    if !focus.is_changed() || *last == focus.focused { return }
+    if let Some(mut animator) = last.and_then(|e| query.get_mut(e).ok()) {
+        animator.animation_direction = -1.0;
+    }
+    if let Some(mut animator) = focus.focused.and_then(|e| query.get_mut(e).ok()) {
+        animator.animation_direction = 1.0;
+    }
+    *last = focus.focused;
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct FocusPlugin;
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<UiFocus>()
+            .add_systems(Update, (ui_focus_navigation, ui_focus_animation).chain());
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use bevy::prelude::Entity;
+    use super::UiFocusable;
+
+    #[test]
+    fn tab_order() {
+        let node = |order: Option<i32>, tree: u32, tree_order: usize| UiFocusable { order, tree: Some(Entity::from_raw(tree)), tree_order };
+        let mut nodes = vec![
+            node(None, 2, 0),
+            node(None, 1, 1),
+            node(Some(1), 2, 5),
+            node(None, 2, 1),
+            node(None, 1, 0),
+            node(Some(0), 1, 3),
+        ];
+        nodes.sort_by_key(UiFocusable::tab_key);
+
+        // Explicit order first, then each tree in its own order without interleaving
+        let order: Vec<(Option<i32>, u32, usize)> = nodes.iter().map(|node| (node.order, node.tree.unwrap().index(), node.tree_order)).collect();
+        assert_eq!(order, [(Some(0), 1, 3), (Some(1), 2, 5), (None, 1, 0), (None, 1, 1), (None, 2, 0), (None, 2, 1)]);
+    }
+}
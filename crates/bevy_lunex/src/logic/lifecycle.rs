@@ -0,0 +1,193 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::hierarchy::HierarchyQueryExt;
+
+use crate::*;
+
+
+// #=========================#
+// #=== LIFECYCLE STRUCTS ===#
+
+/// This struct marks entity to be despawned together with its children and linked subnodes once their [`UiAnimator<Outro>`] animations reach the end.
+/// Insert it using [`UiDespawnCommandsExt::despawn_with_outro`].
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct UiDespawnWithOutro {
+    /// Entities linked to the same [`UiTree`] under the path of the entity
+    pub (crate) subnodes: Vec<Entity>,
+}
+
+/// This struct marks entity to be hidden together with its children and linked subnodes once their [`UiAnimator<Outro>`] animations reach the end.
+/// Insert it using [`UiDespawnCommandsExt::hide_with_outro`] and remove it with [`UiDespawnCommandsExt::show_with_intro`].
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct UiHideWithOutro {
+    /// Entities linked to the same [`UiTree`] under the path of the entity
+    pub (crate) subnodes: Vec<Entity>,
+    /// If the outro finished and the entities are hidden
+    pub (crate) hidden: bool,
+}
+impl UiHideWithOutro {
+    /// Returns if the outro finished and the entities are hidden.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+}
+
+/// Trait adding despawn commands that play [`Outro`] animations first.
+pub trait UiDespawnCommandsExt {
+    /// Plays [`UiAnimator<Outro>`] animations on the entity, all of its children and linked subnodes and despawns them recursively once all of them finish.
+    /// If there are no outro animations, the entity is despawned right away.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use bevy_lunex::prelude::*;
+    /// # fn system(mut commands: Commands, entity: Entity) {
+    /// commands.entity(entity).despawn_with_outro();
+    /// # }
+    /// ```
+    fn despawn_with_outro(&mut self);
+    /// Plays [`UiAnimator<Outro>`] animations on the entity, all of its children and linked subnodes and hides them once all of them finish.
+    fn hide_with_outro(&mut self);
+    /// Shows the entity hidden with [`UiDespawnCommandsExt::hide_with_outro`] and plays [`UiAnimator<Intro>`] animations
+    /// on it, all of its children and linked subnodes again.
+    fn show_with_intro(&mut self);
+}
+impl UiDespawnCommandsExt for EntityCommands<'_> {
+    fn despawn_with_outro(&mut self) {
+        self.insert(UiDespawnWithOutro::default());
+    }
+    fn hide_with_outro(&mut self) {
+        self.insert(UiHideWithOutro::default());
+    }
+    fn show_with_intro(&mut self) {
+        self.add(show_with_intro);
+    }
+}
+
+
+// #=======================#
+// #=== LIFECYCLE LOGIC ===#
+
+/// Returns the entities linked to the same [`UiTree`] under the path of the entity.
+pub (crate) fn linked_subnodes<T: Component>(entity: Entity, links: &Query<(Entity, &UiLink<T>, &Parent)>) -> Vec<Entity> {
+    let Ok((_, link, tree)) = links.get(entity) else { return Vec::new() };
+    let prefix = format!("{}/", link.path);
+    links.iter().filter(|(_, subnode, parent)| parent.get() == tree.get() && subnode.path.starts_with(&prefix)).map(|(subnode, ..)| subnode).collect()
+}
+
+/// Returns the entities together with all of their children.
+fn with_descendants(entities: impl IntoIterator<Item = Entity>, children: &Query<&Children>) -> Vec<Entity> {
+    entities.into_iter().flat_map(|entity| std::iter::once(entity).chain(children.iter_descendants(entity))).collect()
+}
+
+/// Command showing the hidden entity and playing the intro animations of it, its children and linked subnodes.
+fn show_with_intro(entity: Entity, world: &mut World) {
+    let Some(hide) = world.get_entity_mut(entity).and_then(|mut entity| entity.take::<UiHideWithOutro>()) else { return };
+
+    let mut stack = Vec::new();
+    for root in std::iter::once(entity).chain(hide.subnodes) {
+        let Some(mut root) = world.get_entity_mut(root) else { continue };
+        if let Some(mut visibility) = root.get_mut::<Visibility>() { *visibility = Visibility::Inherited; }
+        stack.push(root.id());
+    }
+    while let Some(entity) = stack.pop() {
+        let Some(mut entity) = world.get_entity_mut(entity) else { continue };
+        if let Some(mut animator) = entity.get_mut::<UiAnimator<Outro>>() {
+            animator.animation_transition = 0.0;
+            animator.animation_direction = -1.0;
+            animator.eased_direction = -1.0;
+        }
+        if let Some(mut animator) = entity.get_mut::<UiAnimator<Intro>>() {
+            animator.animation_transition = 1.0;
+            animator.animation_direction = -1.0;
+            animator.eased_direction = -1.0;
+        }
+        if let Some(children) = entity.get::<Children>() { stack.extend(children.iter()); }
+    }
+}
+
+/// System that plays [`Intro`] animation backwards, from the intro layout to the base layout, when the animator is spawned.
+fn ui_intro_system(mut query: Query<&mut UiAnimator<Intro>, Added<UiAnimator<Intro>>>) {
+    for mut animator in &mut query {
+        animator.animation_transition = 1.0;
+        animator.animation_direction = -1.0;
+        animator.eased_direction = -1.0;
+    }
+}
+
+/// System that starts [`Outro`] animations on entities marked for despawn or hide and their children.
+fn ui_outro_start_system(
+    query: Query<Entity, Or<(Added<UiDespawnWithOutro>, Added<UiHideWithOutro>)>>,
+    children: Query<&Children>,
+    mut animators: Query<&mut UiAnimator<Outro>>,
+) {
+    for e in with_descendants(&query, &children) {
+        if let Ok(mut animator) = animators.get_mut(e) {
+            animator.animation_direction = 1.0;
+        }
+    }
+}
+
+/// This system collects the linked subnodes of entities marked for despawn or hide and starts their [`Outro`] animations.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn ui_outro_subnodes_system<T: Component>(
+    mut despawns: Query<(Entity, &mut UiDespawnWithOutro), (Added<UiDespawnWithOutro>, With<UiLink<T>>)>,
+    mut hides: Query<(Entity, &mut UiHideWithOutro), (Added<UiHideWithOutro>, With<UiLink<T>>)>,
+    links: Query<(Entity, &UiLink<T>, &Parent)>,
+    children: Query<&Children>,
+    mut animators: Query<&mut UiAnimator<Outro>>,
+) {
+    let subnodes = despawns.iter_mut().map(|(entity, despawn)| (entity, &mut despawn.into_inner().subnodes))
+        .chain(hides.iter_mut().map(|(entity, hide)| (entity, &mut hide.into_inner().subnodes)));
+    for (entity, subnodes) in subnodes {
+        *subnodes = linked_subnodes(entity, &links);
+        for e in with_descendants(subnodes.iter().copied(), &children) {
+            if let Ok(mut animator) = animators.get_mut(e) {
+                animator.animation_direction = 1.0;
+            }
+        }
+    }
+}
+
+/// System that despawns or hides marked entities once all [`Outro`] animations of them, their children and linked subnodes finish.
+pub (crate) fn ui_outro_finish_system(
+    mut commands: Commands,
+    despawns: Query<(Entity, &UiDespawnWithOutro)>,
+    mut hides: Query<(Entity, &mut UiHideWithOutro)>,
+    children: Query<&Children>,
+    animators: Query<&UiAnimator<Outro>>,
+    mut visibility: Query<&mut Visibility>,
+) {
+    let finished = |entity: Entity, subnodes: &[Entity]| with_descendants(std::iter::once(entity).chain(subnodes.iter().copied()), &children).into_iter().all(|e| {
+        animators.get(e).map(|animator| animator.animation_transition >= 1.0).unwrap_or(true)
+    });
+
+    for (entity, despawn) in &despawns {
+        if !finished(entity, &despawn.subnodes) { continue }
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Despawned after outro", "--".yellow(), "ELEMENT".red());
+        commands.entity(entity).despawn_recursive();
+        for subnode in &despawn.subnodes {
+            if let Some(subnode) = commands.get_entity(*subnode) { subnode.despawn_recursive(); }
+        }
+    }
+
+    for (entity, mut hide) in &mut hides {
+        if hide.hidden || !finished(entity, &hide.subnodes) { continue }
+        for e in std::iter::once(entity).chain(hide.subnodes.iter().copied()) {
+            if let Ok(mut visibility) = visibility.get_mut(e) { *visibility = Visibility::Hidden; }
+        }
+        hide.hidden = true;
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct LifecyclePlugin;
+impl Plugin for LifecyclePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(PreUpdate, ui_intro_system)
+            .add_systems(Update, (ui_outro_start_system, ui_outro_finish_system).chain());
+    }
+}
@@ -0,0 +1,400 @@
+use std::sync::Arc;
+use bevy::{ecs::system::EntityCommands, input::{keyboard::{Key, KeyboardInput}, ButtonState}, window::PrimaryWindow};
+
+use crate::*;
+
+
+// #====================#
+// #=== MENU STRUCTS ===#
+
+/// Callback filling the item entity of a popup with components, it is called with the index and the option.
+pub type UiMenuItemBuilder = Arc<dyn Fn(&mut EntityCommands, usize, &str) + Send + Sync>;
+
+/// **Ui dropdown** - Component holding a list of options, one of which is selected from a popup list.
+/// Clicking the entity opens the popup right below its node, or above it if there is no space below.
+/// In the popup, arrow keys move between the items, typing jumps to the first option starting with the typed text
+/// and `Enter` or a click selects the item. It sends [`UiChangeEvent`] with the selected option.
+/// If the entity has [`Text`], the first section shows the selected option.
+///
+/// The popup is a transparent [`UiModal`], so it blocks picking beneath it and closes on `Escape` or a click outside of it.
+/// The item builder is called for each item entity to give it visuals, the item entities need [`Dimension`] to be picked.
+/// Requires [`UiClickEmitter`] on the entity.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let quality = UiDropdown::new(["Low", "Medium", "High"], |item, _, option| {
+///     item.insert(UiText2dBundle { text: Text::from_section(option, TextStyle::default()), ..default() });
+/// }).selected(1);
+/// ```
+#[derive(Component, Clone)]
+pub struct UiDropdown {
+    /// The options to select from
+    pub options: Vec<String>,
+    /// Index of the selected option
+    pub selected: Option<usize>,
+    /// Height of one item in [`Ab`] units
+    pub item_size: f32,
+    /// Function filling the item entity, it is called with the index and the option
+    pub builder: UiMenuItemBuilder,
+}
+impl UiDropdown {
+    /// Creates new dropdown with the options and the item builder.
+    pub fn new(options: impl IntoIterator<Item = impl Into<String>>, builder: impl Fn(&mut EntityCommands, usize, &str) + Send + Sync + 'static) -> Self {
+        UiDropdown { options: options.into_iter().map(|option| option.into()).collect(), selected: None, item_size: 32.0, builder: Arc::new(builder) }
+    }
+    /// Replaces the selected index with a new value.
+    pub fn selected(mut self, selected: usize) -> Self {
+        self.set_selected(selected);
+        self
+    }
+    /// Replaces the item height with a new value.
+    pub fn item_size(mut self, item_size: f32) -> Self {
+        self.item_size = item_size;
+        self
+    }
+    /// Sets the selected index to a new value, out of range indexes select nothing.
+    pub fn set_selected(&mut self, selected: usize) {
+        self.selected = (selected < self.options.len()).then_some(selected);
+    }
+    /// Returns the selected option.
+    pub fn value(&self) -> Option<&str> {
+        self.selected.and_then(|selected| self.options.get(selected)).map(|option| option.as_str())
+    }
+    /// Selects the index and returns the selected option, if the selection changed.
+    pub (crate) fn select(&mut self, selected: usize) -> Option<&str> {
+        if self.selected == Some(selected) { return None }
+        self.set_selected(selected);
+        self.value()
+    }
+}
+impl std::fmt::Debug for UiDropdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UiDropdown")
+            .field("options", &self.options)
+            .field("selected", &self.selected)
+            .field("item_size", &self.item_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// **Ui context menu** - Component opening a popup list of options at the pointer location on right click.
+/// The pointer location is the [`Cursor2d`] location when it is used. If there is no space right or below
+/// the pointer, the popup opens to the left or above it. Selecting an item sends [`UiChangeEvent`] for the entity
+/// with the option, the keyboard works the same as in [`UiDropdown`] popups.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let menu = UiContextMenu::new(["Copy", "Paste", "Delete"], |item, _, option| {
+///     item.insert(UiText2dBundle { text: Text::from_section(option, TextStyle::default()), ..default() });
+/// }).item_size(Vec2::new(160.0, 28.0));
+/// ```
+#[derive(Component, Clone)]
+pub struct UiContextMenu {
+    /// The options to select from
+    pub options: Vec<String>,
+    /// Size of one item in [`Ab`] units
+    pub item_size: Vec2,
+    /// Function filling the item entity, it is called with the index and the option
+    pub builder: UiMenuItemBuilder,
+}
+impl UiContextMenu {
+    /// Creates new context menu with the options and the item builder.
+    pub fn new(options: impl IntoIterator<Item = impl Into<String>>, builder: impl Fn(&mut EntityCommands, usize, &str) + Send + Sync + 'static) -> Self {
+        UiContextMenu { options: options.into_iter().map(|option| option.into()).collect(), item_size: Vec2::new(200.0, 32.0), builder: Arc::new(builder) }
+    }
+    /// Replaces the item size with a new value.
+    pub fn item_size(mut self, item_size: Vec2) -> Self {
+        self.item_size = item_size;
+        self
+    }
+}
+impl std::fmt::Debug for UiContextMenu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UiContextMenu")
+            .field("options", &self.options)
+            .field("item_size", &self.item_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// This struct marks the popup node spawned by [`UiDropdown`] or [`UiContextMenu`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiMenuPopup {
+    /// The entity with the dropdown or context menu
+    pub source: Entity,
+    /// Index of the item to focus once it is visible
+    pub (crate) focus: Option<usize>,
+}
+
+/// This struct marks an item entity of [`UiMenuPopup`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiMenuItem {
+    /// The entity with the dropdown or context menu
+    pub source: Entity,
+    /// Index of the option
+    pub index: usize,
+}
+
+
+// #==================#
+// #=== MENU LOGIC ===#
+
+/// Seconds after which the typed search text is forgotten.
+const SEARCH_TIMEOUT: f32 = 1.0;
+
+/// Text typed into the top popup to search its options.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct MenuSearch {
+    /// The lowercase search text
+    text: String,
+    /// Seconds since the last typed character
+    idle: f32,
+}
+impl MenuSearch {
+    /// Adds the typed text and returns the index of the first option starting with the search text.
+    fn push(&mut self, text: &str, options: &[String]) -> Option<usize> {
+        if self.idle > SEARCH_TIMEOUT { self.text.clear(); }
+        self.text.push_str(&text.to_lowercase());
+        self.idle = 0.0;
+        options.iter().position(|option| option.to_lowercase().starts_with(&self.text))
+    }
+}
+
+/// Returns the top left corner of the popup opening at the point, flipped and shifted to stay inside the bounds.
+fn context_menu_position(point: Vec2, size: Vec2, bounds: Rect) -> Vec2 {
+    let flip = |point: f32, size: f32, min: f32, max: f32| if point + size > max && point - size >= min { point - size } else { point };
+    let pos = Vec2::new(flip(point.x, size.x, bounds.min.x, bounds.max.x), flip(point.y, size.y, bounds.min.y, bounds.max.y));
+    pos.clamp(bounds.min, (bounds.max - size).max(bounds.min))
+}
+
+/// Spawns the popup node with its items.
+fn spawn_popup<T: Component>(commands: &mut Commands, tree: Entity, path: String, source: Entity, layout: UiLayout, options: &[String], builder: &UiMenuItemBuilder, focus: usize) {
+    commands.entity(tree).with_children(|ui| {
+        ui.spawn((
+            UiNodeBundle::<T> { link: UiLink::<T>::path(&path), layout },
+            UiModal::new().transparent(),
+            UiMenuPopup { source, focus: Some(focus) },
+        ));
+        let height = 100.0 / options.len().max(1) as f32;
+        for (index, option) in options.iter().enumerate() {
+            let mut item = ui.spawn((
+                UiNodeBundle::<T> { link: UiLink::<T>::path(format!("{path}/Item {index}")), layout: UiLayout::window().pos(Rl((0.0, index as f32 * height))).size(Rl((100.0, height))).pack::<Base>() },
+                UiMenuItem { source, index },
+                UiFocusable::new(),
+                UiClickEmitter::SELF,
+            ));
+            builder(&mut item, index, option);
+        }
+    });
+}
+
+/// This system opens the popups of clicked [`UiDropdown`] and right clicked [`UiContextMenu`] entities.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn ui_menu_open_system<T: Component, N: Default + Component>(
+    mut commands: Commands,
+    mut counter: Local<usize>,
+    mut clicks: EventReader<UiClickEvent>,
+    mut downs: EventReader<Pointer<Down>>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    uis: Query<(&UiTree<T, N>, &Dimension, Option<&SourceFromCamera>)>,
+    dropdowns: Query<(&UiDropdown, &UiLink<T>, &Parent)>,
+    menus: Query<(&UiContextMenu, &UiLink<T>, &Parent, &Dimension, Has<Element>, &GlobalTransform)>,
+    popups: Query<&UiMenuPopup, Without<UiDespawnWithOutro>>,
+) {
+    let is_open = |source: Entity| popups.iter().any(|popup| popup.source == source);
+
+    // Dropdowns open below their node
+    for event in clicks.read() {
+        let Ok((dropdown, link, parent)) = dropdowns.get(event.target) else { continue };
+        if is_open(event.target) || dropdown.options.is_empty() { continue }
+        let Some((anchor, abs_scale, bounds)) = uis.get(parent.get()).ok().and_then(|ui| picking::tree_rect(&window, ui, &link.path)) else { continue };
+
+        let size = Vec2::new(anchor.width(), dropdown.item_size * abs_scale * dropdown.options.len() as f32);
+        let position = tooltip_position(anchor, size, UiTooltipPlacement::Below, 0.0, bounds);
+        let layout = UiLayout::window().pos(Ab(position / abs_scale)).size(Ab(size / abs_scale)).pack::<Base>();
+
+        let path = format!("Menu {}", *counter);
+        *counter += 1;
+        spawn_popup::<T>(&mut commands, parent.get(), path, event.target, layout, &dropdown.options, &dropdown.builder, dropdown.selected.unwrap_or(0));
+    }
+
+    // Context menus open at the pointer
+    for event in downs.read() {
+        if event.event.button != PointerButton::Secondary { continue }
+        let Ok((menu, link, parent, dimension, element, transform)) = menus.get(event.target) else { continue };
+        if is_open(event.target) || menu.options.is_empty() { continue }
+        let Some((anchor, abs_scale, bounds)) = uis.get(parent.get()).ok().and_then(|ui| picking::tree_rect(&window, ui, &link.path)) else { continue };
+        let Some(point) = picking::pointer_to_tree(&event.pointer_location, &cameras, &primary_window, anchor, dimension, element, transform) else { continue };
+        let size = menu.item_size * abs_scale * Vec2::new(1.0, menu.options.len() as f32);
+        let position = context_menu_position(point, size, bounds);
+        let layout = UiLayout::window().pos(Ab(position / abs_scale)).size(Ab(size / abs_scale)).pack::<Base>();
+
+        let path = format!("Menu {}", *counter);
+        *counter += 1;
+        spawn_popup::<T>(&mut commands, parent.get(), path, event.target, layout, &menu.options, &menu.builder, 0);
+    }
+}
+
+/// This system focuses the selected item of new popups once it is visible.
+fn ui_menu_focus_system(
+    mut focus: ResMut<UiFocus>,
+    mut popups: Query<&mut UiMenuPopup>,
+    items: Query<(Entity, &UiMenuItem, &ViewVisibility)>,
+) {
+    for mut popup in &mut popups {
+        let Some(index) = popup.focus else { continue };
+        if let Some((entity, ..)) = items.iter().find(|(_, item, visibility)| item.source == popup.source && item.index == index && visibility.get()) {
+            focus.focused = Some(entity);
+            popup.focus = None;
+        }
+    }
+}
+
+/// This system moves the focus to the first item starting with the typed text in the top popup.
+fn ui_menu_search_system(
+    time: Res<Time>,
+    mut search: Local<MenuSearch>,
+    mut keyboard: EventReader<KeyboardInput>,
+    mut focus: ResMut<UiFocus>,
+    modals: Res<UiModalStack>,
+    popups: Query<&UiMenuPopup>,
+    dropdowns: Query<&UiDropdown>,
+    menus: Query<&UiContextMenu>,
+    items: Query<(Entity, &UiMenuItem)>,
+) {
+    search.idle += time.delta_seconds();
+    let Some(popup) = modals.top().and_then(|modal| popups.get(modal).ok()) else { keyboard.clear(); return };
+
+    for event in keyboard.read() {
+        if event.state != ButtonState::Pressed { continue }
+        let Key::Character(character) = &event.logical_key else { continue };
+        let options = match (dropdowns.get(popup.source), menus.get(popup.source)) {
+            (Ok(dropdown), _) => &dropdown.options,
+            (_, Ok(menu)) => &menu.options,
+            _ => continue,
+        };
+        let Some(index) = search.push(character, options) else { continue };
+        if let Some((entity, _)) = items.iter().find(|(_, item)| item.source == popup.source && item.index == index) {
+            focus.focused = Some(entity);
+        }
+    }
+}
+
+/// This system selects clicked items and closes their popups.
+fn ui_menu_select_system(
+    mut commands: Commands,
+    mut clicks: EventReader<UiClickEvent>,
+    items: Query<&UiMenuItem>,
+    popups: Query<(Entity, &UiMenuPopup), Without<UiDespawnWithOutro>>,
+    mut dropdowns: Query<&mut UiDropdown>,
+    menus: Query<&UiContextMenu>,
+    mut change: EventWriter<UiChangeEvent>,
+) {
+    for event in clicks.read() {
+        let Ok(item) = items.get(event.target) else { continue };
+        let Some((popup, _)) = popups.iter().find(|(_, popup)| popup.source == item.source) else { continue };
+        commands.entity(popup).despawn_with_outro();
+
+        if let Ok(mut dropdown) = dropdowns.get_mut(item.source) {
+            // Selecting the same item again does not change the dropdown
+            if let Some(value) = dropdown.bypass_change_detection().select(item.index).map(|value| value.to_owned()) {
+                dropdown.set_changed();
+                change.send(UiChangeEvent { target: item.source, value });
+            }
+        }
+        if let Some(option) = menus.get(item.source).ok().and_then(|menu| menu.options.get(item.index)) {
+            change.send(UiChangeEvent { target: item.source, value: option.clone() });
+        }
+    }
+}
+
+/// This system shows the selected option of dropdowns in their [`Text`].
+fn ui_dropdown_text(mut query: Query<(&UiDropdown, &mut Text), Changed<UiDropdown>>) {
+    for (dropdown, mut text) in &mut query {
+        let value = dropdown.value().unwrap_or_default();
+        if let Some(section) = text.sections.first_mut() {
+            if section.value != value { section.value = value.to_owned(); }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct MenuPlugin;
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (
+                ui_menu_focus_system,
+                ui_menu_search_system,
+                ui_menu_select_system,
+                ui_dropdown_text,
+            ).chain().before(UiSystems::Send));
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{MenuSearch, UiDropdown, SEARCH_TIMEOUT};
+
+    fn quality() -> UiDropdown {
+        UiDropdown::new(["Low", "Medium", "High", "Highest"], |_, _, _| {})
+    }
+
+    #[test]
+    fn search() {
+        let options = quality().options;
+        let mut search = MenuSearch::default();
+        assert_eq!(search.push("h", &options), Some(2));
+        assert_eq!(search.push("I", &options), Some(2));
+        assert_eq!(search.push("ghe", &options), Some(3));
+        assert_eq!(search.push("x", &options), None);
+    }
+
+    #[test]
+    fn search_timeout() {
+        let options = quality().options;
+        let mut search = MenuSearch::default();
+        assert_eq!(search.push("m", &options), Some(1));
+
+        // Typed again before the timeout, the text is appended
+        search.idle = SEARCH_TIMEOUT;
+        assert_eq!(search.push("l", &options), None);
+
+        // Typed after the timeout, the search starts over
+        search.idle = SEARCH_TIMEOUT + 0.1;
+        assert_eq!(search.push("l", &options), Some(0));
+        assert_eq!(search.text, "l");
+    }
+
+    #[test]
+    fn set_selected() {
+        let mut dropdown = quality().selected(3);
+        assert_eq!(dropdown.value(), Some("Highest"));
+
+        dropdown.set_selected(4);
+        assert_eq!(dropdown.selected, None);
+        assert_eq!(dropdown.value(), None);
+
+        dropdown.set_selected(0);
+        assert_eq!(dropdown.value(), Some("Low"));
+        assert_eq!(quality().selected(usize::MAX).selected, None);
+    }
+
+    #[test]
+    fn select_same() {
+        let mut dropdown = quality();
+        assert_eq!(dropdown.select(1), Some("Medium"));
+        assert_eq!(dropdown.select(1), None);
+        assert_eq!(dropdown.select(2), Some("High"));
+        assert_eq!(dropdown.select(9), None);
+        assert_eq!(dropdown.selected, None);
+    }
+}
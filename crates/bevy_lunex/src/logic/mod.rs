@@ -7,12 +7,51 @@ pub use core::*;
 pub mod cursor;
 pub use cursor::*;
 
+pub mod drag_drop;
+pub use drag_drop::*;
+
+pub mod easing;
+pub use easing::*;
+
+pub mod focus;
+pub use focus::*;
+
+pub mod lifecycle;
+pub use lifecycle::*;
+
+pub mod menu;
+pub use menu::*;
+
+pub mod modal;
+pub use modal::*;
+
+pub mod router;
+pub use router::*;
+
+pub mod scroll;
+pub use scroll::*;
+
 pub mod states;
 pub use states::*;
 
 pub mod style;
 pub use style::*;
 
+pub mod text_input;
+pub use text_input::*;
+
+pub mod timeline;
+pub use timeline::*;
+
+pub mod tooltip;
+pub use tooltip::*;
+
+pub mod virtual_list;
+pub use virtual_list::*;
+
+pub mod widgets;
+pub use widgets::*;
+
 
 // #====================#
 // #=== LOGIC PLUGIN ===#
@@ -29,7 +68,18 @@ impl Plugin for UiLogicPlugin {
             .add_plugins(CorePlugin)
             .add_plugins(CursorPlugin)
             .add_plugins(DefaultStatesPlugin)
-            .add_plugins(StylePlugin);
+            .add_plugins(FocusPlugin)
+            .add_plugins(LifecyclePlugin)
+            .add_plugins(MenuPlugin)
+            .add_plugins(ModalPlugin)
+            .add_plugins(RouterPlugin)
+            .add_plugins(ScrollPlugin)
+            .add_plugins(StylePlugin)
+            .add_plugins(TextInputPlugin)
+            .add_plugins(TimelinePlugin)
+            .add_plugins(TooltipPlugin)
+            .add_plugins(VirtualListPlugin)
+            .add_plugins(WidgetsPlugin);
     }
 }
 
@@ -49,6 +99,30 @@ impl <T:Component, N:Default + Component> Plugin for UiStateLogicPlugin<T, N> {
             .add_plugins(StatePlugin::<T, N, Clicked>::new())
             .add_plugins(StatePlugin::<T, N, Selected>::new())
             .add_plugins(StatePlugin::<T, N, Intro>::new())
-            .add_plugins(StatePlugin::<T, N, Outro>::new());
+            .add_plugins(StatePlugin::<T, N, Outro>::new())
+
+            // Tab order of focusable nodes
+            .add_systems(Update, ui_focus_tree_order::<T, N>.after(UiSystems::Compute))
+
+            // Outro animations of linked subnodes
+            .add_systems(Update, ui_outro_subnodes_system::<T>.before(lifecycle::ui_outro_finish_system))
+
+            // Modal layers
+            .add_systems(Update, (ui_modal_open_system::<T>, ui_modal_part_system::<T>).chain().before(UiSystems::Send))
+
+            // Popups of dropdowns and context menus
+            .add_systems(Update, ui_menu_open_system::<T, N>.before(ui_modal_open_system::<T>))
+
+            // Tooltips of hovered nodes
+            .add_systems(Update, (ui_tooltip_system::<T, N>, ui_tooltip_fade_system::<T>).chain().before(UiSystems::Send))
+
+            // Items of virtual lists
+            .add_systems(Update, ui_virtual_list_system::<T, N>.before(UiSystems::Send))
+
+            // Keyframe layouts of timelines
+            .add_systems(Update, (
+                send_layout_to_node::<T, N, TimelineFrom>,
+                send_layout_to_node::<T, N, TimelineTo>,
+            ).in_set(UiSystems::Send).before(send_content_size_to_node::<T, N>));
     }
 }
\ No newline at end of file
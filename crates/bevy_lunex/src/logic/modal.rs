@@ -0,0 +1,253 @@
+use crate::*;
+
+
+// #==============#
+// #=== EVENTS ===#
+
+/// This event is sent when [`UiModal`] is closed by `Escape` or a backdrop click.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiModalCloseEvent {
+    /// The closed modal entity
+    pub modal: Entity,
+}
+
+
+// #=====================#
+// #=== MODAL STRUCTS ===#
+
+/// **Ui modal** - Component turning the node and its subnodes into a modal layer. While it exists:
+/// * A backdrop node covering the whole [`UiTree`] blocks picking of all nodes beneath the modal
+/// * The modal and its subnodes get [`UiDepthBias`] placing them on top of the backdrop
+/// * The focus is trapped inside the modal, see [`UiFocusable`]
+/// * `Escape`, gamepad `B` button or a backdrop click close the modal with [`UiDespawnCommandsExt::despawn_with_outro`]
+///
+/// Modals opened later are placed on top of the earlier ones.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let modal = UiModal::new().backdrop(Color::srgba(0.0, 0.0, 0.0, 0.6)).close_on_backdrop(false);
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiModal {
+    /// Color of the backdrop, `None` makes it transparent
+    pub backdrop: Option<Color>,
+    /// If the modal closes on `Escape` or gamepad `B` button
+    pub close_on_escape: bool,
+    /// If the modal closes on a backdrop click
+    pub close_on_backdrop: bool,
+    /// Depth bias added to the modal for each modal layer
+    pub depth: f32,
+}
+impl UiModal {
+    /// Creates new struct
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Replaces the backdrop color with a new value.
+    pub fn backdrop(mut self, color: Color) -> Self {
+        self.backdrop = Some(color);
+        self
+    }
+    /// Makes the backdrop transparent, it still blocks picking.
+    pub fn transparent(mut self) -> Self {
+        self.backdrop = None;
+        self
+    }
+    /// Replaces the close on escape flag with a new value.
+    pub fn close_on_escape(mut self, close: bool) -> Self {
+        self.close_on_escape = close;
+        self
+    }
+    /// Replaces the close on backdrop flag with a new value.
+    pub fn close_on_backdrop(mut self, close: bool) -> Self {
+        self.close_on_backdrop = close;
+        self
+    }
+    /// Replaces the depth bias with a new value.
+    pub fn depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+}
+impl Default for UiModal {
+    fn default() -> Self {
+        UiModal {
+            backdrop: Some(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            close_on_escape: true,
+            close_on_backdrop: true,
+            depth: 100.0,
+        }
+    }
+}
+
+/// A single open modal layer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub (crate) struct ModalLayer {
+    /// The modal entity
+    pub (crate) modal: Entity,
+    /// The entity focused before the modal was opened
+    pub (crate) focused: Option<Entity>,
+    /// Depth bias of the layer
+    pub (crate) depth: f32,
+}
+
+/// Resource holding the open [`UiModal`] layers, the last one is on top.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct UiModalStack {
+    pub (crate) layers: Vec<ModalLayer>,
+}
+impl UiModalStack {
+    /// Returns the top modal entity.
+    pub fn top(&self) -> Option<Entity> {
+        self.layers.last().map(|layer| layer.modal)
+    }
+    /// Returns if any modal is open.
+    pub fn is_open(&self) -> bool {
+        !self.layers.is_empty()
+    }
+}
+
+/// This struct marks the backdrop of the modal.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiModalBackdrop {
+    /// The modal entity
+    pub modal: Entity,
+}
+
+/// This struct marks the modal, its backdrop and subnodes as part of the modal layer.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiModalPart {
+    /// The modal entity
+    pub modal: Entity,
+}
+
+
+// #===================#
+// #=== MODAL LOGIC ===#
+
+/// This system opens new [`UiModal`] layers and spawns their backdrops.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn ui_modal_open_system<T: Component>(
+    mut commands: Commands,
+    mut counter: Local<usize>,
+    mut stack: ResMut<UiModalStack>,
+    focus: Res<UiFocus>,
+    query: Query<(Entity, &UiModal, &Parent), (With<UiLink<T>>, Added<UiModal>)>,
+) {
+    for (entity, modal, parent) in &query {
+        let depth = stack.layers.last().map(|layer| layer.depth).unwrap_or(0.0) + modal.depth;
+        stack.layers.push(ModalLayer { modal: entity, focused: focus.focused, depth });
+
+        // The backdrop is placed right beneath the modal
+        let path = format!("Modal Backdrop {}", *counter);
+        *counter += 1;
+        commands.entity(parent.get()).with_children(|ui| {
+            ui.spawn((
+                UiNodeBundle::<T> { link: UiLink::<T>::path(path), layout: UiLayout::window_full().pack::<Base>() },
+                UiImage2dBundle { sprite: Sprite { color: modal.backdrop.unwrap_or(Color::NONE), ..default() }, ..default() },
+                UiModalBackdrop { modal: entity },
+                UiModalPart { modal: entity },
+                UiDepthBias(depth - 0.5),
+            ));
+        });
+    }
+}
+
+/// This system marks the subnodes of open [`UiModal`] layers and places them on top of the backdrop.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn ui_modal_part_system<T: Component>(
+    mut commands: Commands,
+    stack: Res<UiModalStack>,
+    modals: Query<&UiLink<T>, With<UiModal>>,
+    query: Query<(Entity, Ref<UiLink<T>>, Option<&UiDepthBias>), Without<UiModalPart>>,
+) {
+    if !stack.is_open() { return }
+    let layers: Vec<(Entity, f32, &String)> = stack.layers.iter().filter_map(|layer| Some((layer.modal, layer.depth, &modals.get(layer.modal).ok()?.path))).collect();
+
+    for (entity, link, bias) in &query {
+        // New layers check all nodes, otherwise only new nodes are checked
+        if !stack.is_changed() && !link.is_added() { continue }
+        let Some((modal, depth, _)) = layers.iter().rev().find(|(_, _, path)| {
+            link.path == **path || link.path.strip_prefix(path.as_str()).map(|rest| rest.starts_with('/')).unwrap_or(false)
+        }) else { continue };
+        commands.entity(entity).insert((UiModalPart { modal: *modal }, UiDepthBias(depth + bias.map(|bias| bias.0).unwrap_or(0.0))));
+    }
+}
+
+/// This system closes the top [`UiModal`] on `Escape` or gamepad `B` button and modals with clicked backdrops.
+fn ui_modal_close_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+    stack: Res<UiModalStack>,
+    mut clicks: EventReader<Pointer<Click>>,
+    backdrops: Query<&UiModalBackdrop>,
+    modals: Query<&UiModal, Without<UiDespawnWithOutro>>,
+    mut close: EventWriter<UiModalCloseEvent>,
+) {
+    let mut closed: Vec<Entity> = Vec::new();
+
+    if keys.just_pressed(KeyCode::Escape) || gamepads.iter().any(|gamepad| buttons.just_pressed(GamepadButton::new(gamepad, GamepadButtonType::East))) {
+        if let Some(top) = stack.top() {
+            if modals.get(top).map(|modal| modal.close_on_escape).unwrap_or(false) { closed.push(top); }
+        }
+    }
+    for event in clicks.read() {
+        let Ok(backdrop) = backdrops.get(event.target) else { continue };
+        if modals.get(backdrop.modal).map(|modal| modal.close_on_backdrop).unwrap_or(false) && !closed.contains(&backdrop.modal) {
+            closed.push(backdrop.modal);
+        }
+    }
+
+    for modal in closed {
+        commands.entity(modal).despawn_with_outro();
+        close.send(UiModalCloseEvent { modal });
+    }
+}
+
+/// This system closes the layers of despawned or despawning modals, restores the focus and despawns their backdrops.
+/// The subnodes of the modal are despawned together with it by [`UiDespawnCommandsExt::despawn_with_outro`].
+fn ui_modal_cleanup_system(
+    mut commands: Commands,
+    mut stack: ResMut<UiModalStack>,
+    mut focus: ResMut<UiFocus>,
+    modals: Query<Has<UiDespawnWithOutro>, With<UiModal>>,
+    backdrops: Query<(Entity, &UiModalBackdrop), Without<UiDespawnWithOutro>>,
+) {
+    // Pop the closed layers
+    if stack.layers.iter().any(|layer| modals.get(layer.modal).unwrap_or(true)) {
+        let top = stack.layers.last().copied();
+        stack.layers.retain(|layer| !modals.get(layer.modal).unwrap_or(true));
+
+        // Give the focus back to the entity focused before the top layer was opened
+        if let Some(top) = top {
+            if stack.top() != Some(top.modal) { focus.focused = top.focused; }
+        }
+    }
+
+    // The backdrop is not under the path of the modal, so it is despawned separately
+    for (entity, backdrop) in &backdrops {
+        match modals.get(backdrop.modal) {
+            Ok(false) => {},
+            Ok(true) => { commands.entity(entity).despawn_with_outro(); },
+            Err(_) => { commands.entity(entity).despawn_recursive(); },
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct ModalPlugin;
+impl Plugin for ModalPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<UiModalStack>()
+            .add_event::<UiModalCloseEvent>()
+            .add_systems(Update, (ui_modal_close_system, ui_modal_cleanup_system).chain());
+    }
+}
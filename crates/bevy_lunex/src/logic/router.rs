@@ -0,0 +1,451 @@
+use std::sync::Arc;
+use bevy::{ecs::world::CommandQueue, utils::HashMap};
+
+use crate::*;
+
+
+// #==============#
+// #=== EVENTS ===#
+
+/// This event is sent when [`UiRouter`] shows a new route.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct UiRouteChangeEvent {
+    /// The entity with the router
+    pub router: Entity,
+    /// The route shown before
+    pub from: Option<String>,
+    /// The shown route
+    pub to: String,
+}
+
+
+// #======================#
+// #=== ROUTER STRUCTS ===#
+
+/// What happens with the entities of the route that is navigated away from.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum UiRouteMode {
+    /// The entities are despawned once their [`UiAnimator<Outro>`] animations finish
+    #[default]
+    Despawn,
+    /// The entities are hidden once their [`UiAnimator<Outro>`] animations finish and shown again with [`Intro`]
+    Hide,
+}
+
+/// Function spawning the entities of a route as children of the router entity.
+pub type UiRouteBuilder = Arc<dyn Fn(&mut ChildBuilder) + Send + Sync>;
+
+/// A navigation waiting for the outgoing route to leave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub (crate) struct RouteRequest {
+    to: String,
+    from: Option<String>,
+    push: bool,
+}
+
+/// **Ui router** - Component mapping route names to builders and showing one route at a time.
+/// Navigating plays [`UiAnimator<Outro>`] of the shown route entities, despawns or hides them based on [`UiRouteMode`],
+/// and then spawns the entities of the new route as children of the router entity, where their [`UiAnimator<Intro>`] plays.
+/// Routes navigated away from are kept in a back stack. Each shown route sends [`UiRouteChangeEvent`].
+///
+/// The builders can spawn a route component following the routes pattern, or linked nodes if the router is on a [`UiTree`] entity.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// # #[derive(Component)] struct MainMenuRoute;
+/// # #[derive(Component)] struct SettingsRoute;
+/// let router = UiRouter::new()
+///     .route("MainMenu", |route| { route.spawn(MainMenuRoute); })
+///     .route("Settings", |route| { route.spawn(SettingsRoute); })
+///     .start("MainMenu");
+/// ```
+#[derive(Component, Default, Clone)]
+pub struct UiRouter {
+    /// The route names and their builders
+    pub (crate) routes: HashMap<String, UiRouteBuilder>,
+    /// What happens with routes navigated away from
+    pub mode: UiRouteMode,
+    /// The shown route
+    pub (crate) current: Option<String>,
+    /// Routes to go back to, the last one is the most recent
+    pub (crate) history: Vec<String>,
+    /// The navigation in progress
+    pub (crate) pending: Option<RouteRequest>,
+    /// Spawned entities of each route
+    pub (crate) pages: HashMap<String, Vec<Entity>>,
+    /// Entities of the outgoing route playing their outro
+    pub (crate) leaving: Vec<Entity>,
+}
+impl UiRouter {
+    /// Creates new router without routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds the route with the builder.
+    pub fn route(mut self, name: impl Into<String>, builder: impl Fn(&mut ChildBuilder) + Send + Sync + 'static) -> Self {
+        self.routes.insert(name.into(), Arc::new(builder));
+        self
+    }
+    /// Replaces the route mode with a new value.
+    pub fn mode(mut self, mode: UiRouteMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    /// Shows the route once the router is spawned.
+    pub fn start(mut self, name: impl Into<String>) -> Self {
+        self.replace(name);
+        self
+    }
+    /// Navigates to the route and puts the shown route on the back stack.
+    pub fn navigate(&mut self, name: impl Into<String>) {
+        self.request(name.into(), true);
+    }
+    /// Navigates to the route without changing the back stack.
+    pub fn replace(&mut self, name: impl Into<String>) {
+        self.request(name.into(), false);
+    }
+    /// Navigates to the most recent route on the back stack. Returns `false` if the back stack is empty.
+    pub fn back(&mut self) -> bool {
+        let Some(name) = self.history.pop() else { return false };
+        self.request(name, false);
+        true
+    }
+    /// Returns the shown route, it is `None` while the outgoing route is leaving.
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+    /// Returns the back stack, the last route is the most recent.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+    /// Returns if there is a route to go back to.
+    pub fn can_go_back(&self) -> bool {
+        !self.history.is_empty()
+    }
+    /// Queues the navigation, a navigation in progress changes its target and if it pushes the shown route.
+    fn request(&mut self, to: String, push: bool) {
+        match &mut self.pending {
+            Some(request) => {
+                request.to = to;
+                request.push = push;
+            },
+            None => {
+                if self.current.as_ref() == Some(&to) { return }
+                self.pending = Some(RouteRequest { to, from: self.current.clone(), push });
+            },
+        }
+    }
+    /// Takes the shown route when it starts leaving and puts it on the back stack if the navigation pushes it.
+    fn leave(&mut self) -> Option<String> {
+        let current = self.current.take()?;
+        if self.pending.as_ref().map(|request| request.push).unwrap_or(false) { self.history.push(current.clone()); }
+        Some(current)
+    }
+}
+impl std::fmt::Debug for UiRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UiRouter")
+            .field("routes", &self.routes.keys().collect::<Vec<_>>())
+            .field("mode", &self.mode)
+            .field("current", &self.current)
+            .field("history", &self.history)
+            .field("pending", &self.pending)
+            .field("pages", &self.pages)
+            .field("leaving", &self.leaving)
+            .finish()
+    }
+}
+
+/// This struct marks entities spawned by the route builder of [`UiRouter`].
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct UiRoutePage {
+    /// The entity with the router
+    pub router: Entity,
+    /// Name of the route
+    pub route: String,
+}
+
+/// **Ui tab** - Component making the entity a tab button of [`UiRouter`]. Clicking it shows the route
+/// without changing the back stack and the tab of the shown route plays [`UiAnimator<Selected>`].
+/// Requires [`UiClickEmitter`] on the entity.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// # fn system(mut commands: Commands, router: Entity) {
+/// commands.spawn((UiTab::new(router, "Audio"), UiClickEmitter::SELF));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct UiTab {
+    /// The entity with the router
+    pub router: Entity,
+    /// Name of the route
+    pub route: String,
+}
+impl UiTab {
+    /// Creates new tab showing the route.
+    pub fn new(router: Entity, route: impl Into<String>) -> Self {
+        UiTab { router, route: route.into() }
+    }
+}
+
+
+// #====================#
+// #=== ROUTER LOGIC ===#
+
+/// This system moves [`UiRouter`] entities through their navigations.
+fn ui_router_system(
+    mut commands: Commands,
+    mut routers: Query<(Entity, &mut UiRouter)>,
+    pages: Query<(), With<UiRoutePage>>,
+    hides: Query<&UiHideWithOutro>,
+    mut events: EventWriter<UiRouteChangeEvent>,
+) {
+    for (entity, mut router) in &mut routers {
+        if router.pending.is_none() && router.leaving.is_empty() { continue }
+        let router = router.bypass_change_detection();
+
+        // Start the outro of the shown route
+        if let Some(current) = router.leave() {
+            let leaving = match router.mode {
+                UiRouteMode::Despawn => router.pages.remove(&current).unwrap_or_default(),
+                UiRouteMode::Hide => router.pages.get(&current).cloned().unwrap_or_default(),
+            };
+            for page in &leaving {
+                match router.mode {
+                    UiRouteMode::Despawn => commands.entity(*page).despawn_with_outro(),
+                    UiRouteMode::Hide => commands.entity(*page).hide_with_outro(),
+                }
+            }
+            router.leaving = leaving;
+        }
+
+        // Wait until the outgoing route is despawned or hidden
+        match router.mode {
+            UiRouteMode::Despawn => router.leaving.retain(|page| pages.contains(*page)),
+            UiRouteMode::Hide => router.leaving.retain(|page| pages.contains(*page) && !hides.get(*page).map(|hide| hide.is_hidden()).unwrap_or(false)),
+        }
+        if !router.leaving.is_empty() { continue }
+
+        // Show the incoming route
+        let Some(request) = router.pending.take() else { continue };
+        let Some(builder) = router.routes.get(&request.to).cloned() else {
+            warn!("Route {} does not exist in the router", request.to);
+            continue;
+        };
+        match router.pages.get(&request.to) {
+            Some(hidden) => for page in hidden {
+                commands.entity(*page).show_with_intro();
+            },
+            None => {
+                let route = request.to.clone();
+                commands.add(move |world: &mut World| spawn_route(world, entity, route, builder));
+            },
+        }
+        router.current = Some(request.to.clone());
+        events.send(UiRouteChangeEvent { router: entity, from: request.from, to: request.to });
+    }
+}
+
+/// Spawns the route entities with the builder and marks them as its pages.
+fn spawn_route(world: &mut World, router: Entity, route: String, builder: UiRouteBuilder) {
+    if world.get_entity(router).is_none() { return }
+    let before: Vec<Entity> = world.get::<Children>(router).map(|children| children.to_vec()).unwrap_or_default();
+
+    let mut queue = CommandQueue::default();
+    Commands::new(&mut queue, world).entity(router).with_children(|ui| builder(ui));
+    queue.apply(world);
+
+    let spawned: Vec<Entity> = world.get::<Children>(router).map(|children| children.iter().filter(|e| !before.contains(e)).copied().collect()).unwrap_or_default();
+    for page in &spawned {
+        world.entity_mut(*page).insert(UiRoutePage { router, route: route.clone() });
+    }
+    if let Some(mut component) = world.get_mut::<UiRouter>(router) {
+        component.bypass_change_detection().pages.insert(route, spawned);
+    }
+}
+
+/// This system shows the routes of clicked [`UiTab`] entities.
+fn ui_tab_click(mut events: EventReader<UiClickEvent>, tabs: Query<&UiTab>, mut routers: Query<&mut UiRouter>) {
+    for event in events.read() {
+        let Ok(tab) = tabs.get(event.target) else { continue };
+        if let Ok(mut router) = routers.get_mut(tab.router) { router.replace(tab.route.clone()); }
+    }
+}
+
+/// This system plays [`UiAnimator<Selected>`] of tabs with the shown route.
+fn ui_tab_animation(mut events: EventReader<UiRouteChangeEvent>, mut tabs: Query<(&UiTab, &mut UiAnimator<Selected>)>) {
+    for event in events.read() {
+        for (tab, mut animator) in &mut tabs {
+            if tab.router != event.router { continue }
+            animator.animation_direction = if tab.route == event.to { 1.0 } else { -1.0 };
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct RouterPlugin;
+impl Plugin for RouterPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<UiRouteChangeEvent>()
+            .add_systems(Update, (ui_tab_click, ui_router_system, ui_tab_animation).chain().before(UiSystems::Send));
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use super::ui_router_system;
+
+    /// Creates app with the router and lifecycle systems and a router showing the main route.
+    fn app(mode: UiRouteMode) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_event::<UiRouteChangeEvent>()
+            .add_plugins(LifecyclePlugin)
+            .add_systems(Update, ui_router_system);
+        let router = UiRouter::new().mode(mode).start("Main");
+        let router = ["Main", "Settings", "Audio", "Credits"].into_iter().fold(router, |router, route| {
+            router.route(route, |ui| { ui.spawn(Visibility::default()); })
+        });
+        let router = app.world_mut().spawn(router).id();
+        settle(&mut app);
+        (app, router)
+    }
+
+    /// Runs the updates until the navigation in progress finishes.
+    fn settle(app: &mut App) {
+        for _ in 0..4 { app.update(); }
+    }
+
+    fn router(app: &mut App, router: Entity) -> Mut<'_, UiRouter> {
+        app.world_mut().get_mut::<UiRouter>(router).unwrap()
+    }
+
+    /// Returns the page entities of the route.
+    fn pages(app: &mut App, route: &str) -> Vec<Entity> {
+        let mut query = app.world_mut().query::<(Entity, &UiRoutePage)>();
+        query.iter(app.world()).filter(|(_, page)| page.route == route).map(|(entity, _)| entity).collect()
+    }
+
+    #[test]
+    fn start() {
+        let (mut app, entity) = app(UiRouteMode::Despawn);
+        assert_eq!(router(&mut app, entity).current(), Some("Main"));
+        assert!(!router(&mut app, entity).can_go_back());
+        assert_eq!(pages(&mut app, "Main").len(), 1);
+    }
+
+    #[test]
+    fn navigate() {
+        let (mut app, entity) = app(UiRouteMode::Despawn);
+        router(&mut app, entity).navigate("Settings");
+        settle(&mut app);
+        router(&mut app, entity).navigate("Audio");
+        settle(&mut app);
+        assert_eq!(router(&mut app, entity).current(), Some("Audio"));
+        assert_eq!(router(&mut app, entity).history(), ["Main", "Settings"]);
+
+        // Navigating to the shown route does nothing
+        router(&mut app, entity).navigate("Audio");
+        assert!(router(&mut app, entity).pending.is_none());
+        assert_eq!(router(&mut app, entity).history(), ["Main", "Settings"]);
+    }
+
+    #[test]
+    fn replace() {
+        let (mut app, entity) = app(UiRouteMode::Despawn);
+        router(&mut app, entity).navigate("Settings");
+        settle(&mut app);
+        router(&mut app, entity).replace("Credits");
+        settle(&mut app);
+        assert_eq!(router(&mut app, entity).current(), Some("Credits"));
+        assert_eq!(router(&mut app, entity).history(), ["Main"]);
+    }
+
+    #[test]
+    fn back() {
+        let (mut app, entity) = app(UiRouteMode::Despawn);
+        router(&mut app, entity).navigate("Settings");
+        settle(&mut app);
+        router(&mut app, entity).navigate("Audio");
+        settle(&mut app);
+
+        assert!(router(&mut app, entity).back());
+        settle(&mut app);
+        assert_eq!(router(&mut app, entity).current(), Some("Settings"));
+        assert_eq!(router(&mut app, entity).history(), ["Main"]);
+
+        assert!(router(&mut app, entity).back());
+        settle(&mut app);
+        assert_eq!(router(&mut app, entity).current(), Some("Main"));
+        assert!(!router(&mut app, entity).can_go_back());
+        assert!(!router(&mut app, entity).back());
+    }
+
+    #[test]
+    fn pending() {
+        // A navigation in progress takes the target and the back stack behavior of the last request
+        let (mut app, entity) = app(UiRouteMode::Despawn);
+        router(&mut app, entity).navigate("Settings");
+        router(&mut app, entity).replace("Audio");
+        settle(&mut app);
+        assert_eq!(router(&mut app, entity).current(), Some("Audio"));
+        assert!(router(&mut app, entity).history().is_empty());
+
+        router(&mut app, entity).replace("Credits");
+        router(&mut app, entity).navigate("Settings");
+        settle(&mut app);
+        assert_eq!(router(&mut app, entity).current(), Some("Settings"));
+        assert_eq!(router(&mut app, entity).history(), ["Audio"]);
+
+        // Going back never pushes the shown route
+        router(&mut app, entity).navigate("Credits");
+        assert!(router(&mut app, entity).back());
+        settle(&mut app);
+        assert_eq!(router(&mut app, entity).current(), Some("Audio"));
+        assert!(router(&mut app, entity).history().is_empty());
+    }
+
+    #[test]
+    fn despawn_round_trip() {
+        let (mut app, entity) = app(UiRouteMode::Despawn);
+        let main = pages(&mut app, "Main");
+        router(&mut app, entity).navigate("Settings");
+        settle(&mut app);
+        assert!(main.iter().all(|page| app.world().get_entity(*page).is_none()));
+        assert_eq!(pages(&mut app, "Settings").len(), 1);
+
+        // The route is spawned again
+        router(&mut app, entity).back();
+        settle(&mut app);
+        assert!(pages(&mut app, "Settings").is_empty());
+        assert_eq!(pages(&mut app, "Main").len(), 1);
+        assert_ne!(pages(&mut app, "Main"), main);
+    }
+
+    #[test]
+    fn hide_round_trip() {
+        let (mut app, entity) = app(UiRouteMode::Hide);
+        let main = pages(&mut app, "Main");
+        router(&mut app, entity).navigate("Settings");
+        settle(&mut app);
+        assert_eq!(pages(&mut app, "Main"), main);
+        assert_eq!(app.world().get::<Visibility>(main[0]), Some(&Visibility::Hidden));
+        assert!(app.world().get::<UiHideWithOutro>(main[0]).unwrap().is_hidden());
+
+        // The hidden route is shown again instead of spawned
+        let settings = pages(&mut app, "Settings");
+        router(&mut app, entity).back();
+        settle(&mut app);
+        assert_eq!(router(&mut app, entity).current(), Some("Main"));
+        assert_eq!(pages(&mut app, "Main"), main);
+        assert_eq!(app.world().get::<Visibility>(main[0]), Some(&Visibility::Inherited));
+        assert!(app.world().get::<UiHideWithOutro>(main[0]).is_none());
+        assert_eq!(app.world().get::<Visibility>(settings[0]), Some(&Visibility::Hidden));
+    }
+}
@@ -0,0 +1,169 @@
+use bevy::{input::mouse::{MouseScrollUnit, MouseWheel}, utils::HashMap, window::PrimaryWindow};
+
+use crate::*;
+
+
+// #======================#
+// #=== SCROLL STRUCTS ===#
+
+/// This struct turns the entity into a scrollbar thumb of [`UiScroll`] container.
+/// It overwrites the [`UiLayout`] of the entity with a window layout that is sized and positioned
+/// relative to its parent node, so it should be placed inside a track node.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// # fn system(container: Entity) {
+/// let scrollbar = UiScrollbar::new(container, StackDirection::Vertical);
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiScrollbar {
+    /// The entity with [`UiScroll`]
+    pub target: Entity,
+    /// The scrolled axis
+    pub direction: StackDirection,
+}
+impl UiScrollbar {
+    /// Creates new struct
+    pub fn new(target: Entity, direction: StackDirection) -> Self {
+        UiScrollbar { target, direction }
+    }
+}
+
+/// This struct marks entity that handles pointer drags itself, so dragging it does not scroll [`UiScroll`] containers.
+/// Widgets that are dragged insert it on their own, insert it on custom widgets that are dragged too.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UiDragCapture;
+
+
+// #====================#
+// #=== SCROLL LOGIC ===#
+
+/// How many wheel lines per second the gamepad right stick scrolls at full tilt.
+const STICK_LINES_PER_SECOND: f32 = 20.0;
+
+/// This system scrolls the innermost [`UiScroll`] container under each pointer on mouse wheel, pointer drag
+/// and gamepad right stick input. Released containers keep scrolling with their velocity until friction stops them.
+fn ui_scroll_system(
+    time: Res<Time>,
+    mut wheel: EventReader<MouseWheel>,
+    mut drag: EventReader<Pointer<Drag>>,
+    axis: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    captures: Query<(), With<UiDragCapture>>,
+    mut query: Query<(Entity, &Dimension, Has<Element>, &GlobalTransform, Option<&UiClip>, &ViewVisibility, &mut UiScroll)>,
+) {
+    let delta = time.delta_seconds();
+
+    // Finds the innermost container under the pointer location
+    let container = |location: &bevy_mod_picking::pointer::Location| {
+        let (_, _, ray) = picking::pointer_ray(location, &cameras, &primary_window)?;
+        query.iter().filter(|(.., visibility, _)| visibility.get()).filter_map(|(entity, dimension, element, transform, clip, ..)| {
+            let position = picking::ray_hit_node(ray, dimension, element, transform, clip)?;
+            Some((entity, ray.origin.distance(position)))
+        }).min_by(|a, b| a.1.total_cmp(&b.1)).map(|(entity, _)| entity)
+    };
+
+    // Scroll amounts and dragged containers this frame
+    let mut scrolled: HashMap<Entity, Vec2> = HashMap::new();
+    let mut dragged: HashMap<Entity, Vec2> = HashMap::new();
+
+    // Mouse wheel
+    let lines: Vec<(Vec2, bool)> = wheel.read().map(|event| (Vec2::new(event.x, event.y), event.unit == MouseScrollUnit::Line)).collect();
+    if !lines.is_empty() {
+        if let Some(target) = pointers.iter().find(|(id, _)| **id == PointerId::Mouse).and_then(|(_, location)| location.location()).and_then(container) {
+            *scrolled.entry(target).or_default() += lines.iter().map(|(amount, is_line)| {
+                let speed = if *is_line { query.get(target).map(|(.., scroll)| scroll.speed).unwrap_or(1.0) } else { 1.0 };
+                -*amount * speed
+            }).sum::<Vec2>();
+        }
+    }
+
+    // Gamepad right stick
+    let stick: Vec2 = gamepads.iter().map(|gamepad| Vec2::new(
+        axis.get(GamepadAxis::new(gamepad, GamepadAxisType::RightStickX)).unwrap_or(0.0),
+        axis.get(GamepadAxis::new(gamepad, GamepadAxisType::RightStickY)).unwrap_or(0.0),
+    )).sum();
+    if stick.length() > 0.1 {
+        for (_, location) in &pointers {
+            if let Some(target) = location.location().and_then(container) {
+                let speed = query.get(target).map(|(.., scroll)| scroll.speed).unwrap_or(1.0);
+                *scrolled.entry(target).or_default() += Vec2::new(stick.x, -stick.y) * speed * STICK_LINES_PER_SECOND * delta;
+            }
+        }
+    }
+
+    // Pointer dragging, the delta is in window coordinates that are Y down like the offset
+    for event in drag.read() {
+        if captures.contains(event.target) { continue }
+        if let Some(target) = container(&event.pointer_location) {
+            *dragged.entry(target).or_default() -= event.delta;
+        }
+    }
+
+    for (entity, .., mut scroll) in &mut query {
+        let mut new = *scroll;
+
+        if let Some(amount) = scrolled.get(&entity) {
+            // Wheel scrolls horizontally if only that axis is allowed
+            let amount = if new.axis == BVec2::new(true, false) { Vec2::new(amount.x + amount.y, 0.0) } else { *amount };
+            new.offset += amount;
+            new.velocity = Vec2::ZERO;
+        }
+        if let Some(amount) = dragged.get(&entity) {
+            new.offset += *amount;
+            if delta > 0.0 { new.velocity = *amount / delta; }
+        } else if new.velocity != Vec2::ZERO {
+            // Keep scrolling after release
+            new.offset += new.velocity * delta;
+            new.velocity *= (-new.friction * delta).exp();
+            if new.velocity.length() < 1.0 { new.velocity = Vec2::ZERO; }
+        }
+
+        // Clamp to the content and allowed axes
+        let max = new.max_offset();
+        new.offset = Vec2::select(new.axis, new.offset.clamp(Vec2::ZERO, max), Vec2::ZERO);
+        new.velocity = Vec2::select(new.axis, new.velocity, Vec2::ZERO);
+        if new.offset.x <= 0.0 || new.offset.x >= max.x { new.velocity.x = 0.0; }
+        if new.offset.y <= 0.0 || new.offset.y >= max.y { new.velocity.y = 0.0; }
+
+        if *scroll != new { *scroll = new; }
+    }
+}
+
+/// This system sizes and positions [`UiScrollbar`] thumbs to match the scrolled offset of their containers.
+fn ui_scrollbar_system(
+    containers: Query<&UiScroll>,
+    mut query: Query<(&UiScrollbar, &mut UiLayout)>,
+) {
+    for (scrollbar, mut layout) in &mut query {
+        let Ok(scroll) = containers.get(scrollbar.target) else { continue };
+        let (offset, view, content) = match scrollbar.direction {
+            StackDirection::Horizontal => (scroll.offset.x, scroll.view.x, scroll.content.x),
+            StackDirection::Vertical => (scroll.offset.y, scroll.view.y, scroll.content.y),
+        };
+
+        // Fraction of the track covered by the thumb and its start
+        let (size, pos) = if content > view && content > 0.0 { (view / content, offset / content) } else { (1.0, 0.0) };
+        let new: UiLayout = match scrollbar.direction {
+            StackDirection::Horizontal => UiLayout::window().pos(Rl((pos * 100.0, 0.0))).size(Rl((size * 100.0, 100.0))).pack(),
+            StackDirection::Vertical => UiLayout::window().pos(Rl((0.0, pos * 100.0))).size(Rl((100.0, size * 100.0))).pack(),
+        };
+        if layout.layout != new.layout { layout.layout = new.layout; }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct ScrollPlugin;
+impl Plugin for ScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (ui_scroll_system, ui_scrollbar_system).chain().before(UiSystems::Send));
+    }
+}
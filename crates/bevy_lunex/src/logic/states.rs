@@ -33,7 +33,14 @@ fn set_ui_state_transition<S: UiState>(mut events: EventReader<SetUiStateTransit
 // #=====================#
 // #=== STATE STRUCTS ===#
 
-/// Control struct for the button state
+/// Control struct for the button state.
+/// It animates the weight of the `S` layout in [`UiLayoutController`] and the `S` color in [`UiColor`].
+/// When multiple animators are active on one entity, the differences of their layouts from the base layout are added together.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let animator = UiAnimator::<Hover>::new().forward_ease(UiEase::Back(EaseMode::Out)).backward_ease(UiEase::Quad(EaseMode::In));
+/// ```
 #[derive(Component, Debug, Clone, PartialEq)]
 pub struct UiAnimator<S: UiState> {
     marker: PhantomData<S>,
@@ -41,12 +48,19 @@ pub struct UiAnimator<S: UiState> {
     pub (crate) animation_direction: f32,
     /// Range from `0.0` to `1.0`
     pub (crate) animation_transition: f32,
-    /// Setting this to true will disable logic with intention that something else will pipe the control data instead
+    /// Direction the current transition was eased in
+    pub (crate) eased_direction: f32,
+    /// Setting this to true will disable logic with intention that something else will pipe the control data instead.
+    /// The piped data is already eased, so receivers should keep the default linear curves
     pub receiver: bool,
     /// Hover animation speed when transitioning to state
     pub animation_speed_forward: f32,
     /// Hover animation speed when transitioning back to default
     pub animation_speed_backward: f32,
+    /// Easing curve used when transitioning to state
+    pub animation_ease_forward: UiEase,
+    /// Easing curve used when transitioning back to default, it is played in reverse
+    pub animation_ease_backward: UiEase,
 }
 impl <S: UiState> UiAnimator<S> {
     /// Creates new struct
@@ -68,10 +82,48 @@ impl <S: UiState> UiAnimator<S> {
         self.animation_speed_backward = speed;
         self
     }
+    /// Replaces the forward and backward easing curves with a new value.
+    pub fn ease(mut self, ease: UiEase) -> Self {
+        self.animation_ease_forward = ease;
+        self.animation_ease_backward = ease;
+        self
+    }
+    /// Replaces the forward easing curve with a new value.
+    pub fn forward_ease(mut self, ease: UiEase) -> Self {
+        self.animation_ease_forward = ease;
+        self
+    }
+    /// Replaces the backward easing curve with a new value.
+    pub fn backward_ease(mut self, ease: UiEase) -> Self {
+        self.animation_ease_backward = ease;
+        self
+    }
+    /// Sets the forward and backward easing curves to a new value.
+    pub fn set_ease(&mut self, ease: UiEase) {
+        self.animation_ease_forward = ease;
+        self.animation_ease_backward = ease;
+    }
+    /// Sets the forward easing curve to a new value.
+    pub fn set_forward_ease(&mut self, ease: UiEase) {
+        self.animation_ease_forward = ease;
+    }
+    /// Sets the backward easing curve to a new value.
+    pub fn set_backward_ease(&mut self, ease: UiEase) {
+        self.animation_ease_backward = ease;
+    }
     /// Checks if animation is moving forward
     pub fn is_forward(&self) -> bool {
         self.animation_direction == 1.0
     }
+    /// Returns the eased transition value. It can overshoot the `0.0` to `1.0` range.
+    pub fn value(&self) -> f32 {
+        eased_value(self.animation_transition, self.eased_direction, self.animation_ease_forward, self.animation_ease_backward)
+    }
+}
+
+/// Eases the transition with the curve of the direction. The backward curve is played in reverse.
+fn eased_value(transition: f32, direction: f32, forward: UiEase, backward: UiEase) -> f32 {
+    if direction.is_sign_positive() { forward.ease(transition) } else { 1.0 - backward.ease(1.0 - transition) }
 }
 impl <S: UiState> Default for UiAnimator<S> {
     fn default() -> Self {
@@ -79,30 +131,53 @@ impl <S: UiState> Default for UiAnimator<S> {
             marker: PhantomData,
             animation_direction: -1.0,
             animation_transition: 0.0,
+            eased_direction: -1.0,
             receiver: false,
             animation_speed_backward: 8.0,
             animation_speed_forward: 8.0,
+            animation_ease_forward: UiEase::Linear,
+            animation_ease_backward: UiEase::Linear,
         }
     }
 }
 fn ui_animation<S: UiState>(time: Res<Time>, mut query: Query<&mut UiAnimator<S>>) {
     for mut control in &mut query {
         if control.receiver { continue }
+
+        // Continue from the same value when the direction changes midway. Curves that are not monotonic
+        // can't be inverted, so the transition keeps the previous curve until it reaches the end.
+        if control.animation_direction != control.eased_direction {
+            let value = control.value();
+            let transition = if control.animation_transition == 0.0 || control.animation_transition == 1.0 {
+                Some(control.animation_transition)
+            } else if control.animation_direction.is_sign_positive() {
+                control.animation_ease_forward.progress(value)
+            } else {
+                control.animation_ease_backward.progress(1.0 - value).map(|progress| 1.0 - progress)
+            };
+            if let Some(transition) = transition {
+                control.eased_direction = control.animation_direction;
+                control.animation_transition = transition;
+            }
+        }
+
         if !(
             (control.animation_transition == 0.0 && control.animation_direction.is_sign_negative()) ||
             (control.animation_transition == 1.0 && control.animation_direction.is_sign_positive())
         ) {
             #[cfg(feature = "verbose")]
             info!("{} {} - Transitioning ... dir: {} transition: {}", "--".yellow(), "ELEMENT".red(), control.animation_direction, control.animation_transition);
-            control.animation_transition += time.delta_seconds() * control.animation_direction * if control.animation_direction == 1.0 { control.animation_speed_forward } else { control.animation_speed_backward };
+            // Springs have their own duration instead of speed
+            let (speed, ease) = if control.animation_direction == 1.0 { (control.animation_speed_forward, control.animation_ease_forward) } else { (control.animation_speed_backward, control.animation_ease_backward) };
+            let speed = ease.duration().map(|duration| 1.0 / duration).unwrap_or(speed);
+            control.animation_transition += time.delta_seconds() * control.animation_direction * speed;
             control.animation_transition = control.animation_transition.clamp(0.0, 1.0);
         }
     }
 }
 fn ui_animation_state<S: UiState>(mut query: Query<(&UiAnimator<S>, &mut UiLayoutController), Changed<UiAnimator<S>>>) {
     for (animator, mut controller) in &mut query {
-        controller.index[1] = Hover::INDEX;
-        controller.tween = animator.animation_transition;
+        controller.set_weight(S::INDEX, animator.value());
     }
 }
 
@@ -127,7 +202,7 @@ impl <S: UiState> UiAnimatorPipe<S> {
 fn ui_state_pipe_system<S: UiState>(query: Query<(&UiAnimator<S>, &UiAnimatorPipe<S>), Changed<UiAnimator<S>>>, mut event: EventWriter<SetUiStateTransition<S>>) {
     for (state, pipe) in &query {
         for e in &pipe.entity {
-            event.send(SetUiStateTransition::new(*e, state.animation_transition));
+            event.send(SetUiStateTransition::new(*e, state.value()));
         }
     }
 }
@@ -154,7 +229,7 @@ fn set_ui_color<S: UiState>(query: Query<(&UiAnimator<S>, &UiColor<Base>, &UiCol
     for (hover, basecolor, hovercolor, entity) in &query {
         set_color.send(actions::SetColor {
             target: entity,
-            color: basecolor.color.lerp(hovercolor.color, hover.animation_transition),
+            color: basecolor.color.lerp(hovercolor.color, hover.value()),
         });
     }
 }
@@ -0,0 +1,650 @@
+use std::ops::Range;
+use bevy::{input::{keyboard::{Key, KeyboardInput}, ButtonState}, window::{Ime, PrimaryWindow}};
+
+use crate::*;
+
+
+// #==============#
+// #=== EVENTS ===#
+
+/// This event is sent when `Enter` is pressed in a focused [`UiTextInput`].
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct UiSubmitEvent {
+    /// The entity with the text input
+    pub target: Entity,
+    /// The submitted value
+    pub value: String,
+}
+
+
+// #=================#
+// #=== CLIPBOARD ===#
+
+/// Trait for clipboards used by [`UiTextInput`] to copy, cut and paste text.
+/// Implement it to connect the system clipboard and insert it with [`UiClipboard::new`].
+pub trait UiClipboardProvider: Send + Sync + 'static {
+    /// Returns the text in the clipboard.
+    fn get(&mut self) -> Option<String>;
+    /// Puts the text into the clipboard.
+    fn set(&mut self, text: String);
+}
+
+/// Clipboard that keeps the text in memory, shared only within the app.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct UiMemoryClipboard(pub Option<String>);
+impl UiClipboardProvider for UiMemoryClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.0.clone()
+    }
+    fn set(&mut self, text: String) {
+        self.0 = Some(text);
+    }
+}
+
+/// Resource holding the clipboard used by text inputs. Defaults to [`UiMemoryClipboard`].
+#[derive(Resource)]
+pub struct UiClipboard(Box<dyn UiClipboardProvider>);
+impl UiClipboard {
+    /// Creates new clipboard from the provider.
+    pub fn new(provider: impl UiClipboardProvider) -> Self {
+        UiClipboard(Box::new(provider))
+    }
+    /// Returns the text in the clipboard.
+    pub fn get(&mut self) -> Option<String> {
+        self.0.get()
+    }
+    /// Puts the text into the clipboard.
+    pub fn set(&mut self, text: String) {
+        self.0.set(text)
+    }
+}
+impl Default for UiClipboard {
+    fn default() -> Self {
+        UiClipboard::new(UiMemoryClipboard::default())
+    }
+}
+
+
+// #==========================#
+// #=== TEXT INPUT STRUCTS ===#
+
+/// Validation filter of [`UiTextInput`]. Edits producing a value it rejects are ignored.
+#[allow(unpredictable_function_pointer_comparisons)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum UiTextFilter {
+    /// Any text is accepted.
+    #[default]
+    Any,
+    /// Whole numbers with an optional leading minus sign.
+    Integer,
+    /// Decimal numbers with an optional leading minus sign.
+    Decimal,
+    /// Letters and digits only.
+    Alphanumeric,
+    /// Custom function checking the whole value.
+    Custom(fn(&str) -> bool),
+}
+impl UiTextFilter {
+    /// Checks if the value is accepted. Unfinished numbers like `-` or `1.` are accepted too.
+    pub fn accepts(&self, value: &str) -> bool {
+        let unsigned = value.strip_prefix('-').unwrap_or(value);
+        match self {
+            UiTextFilter::Any => true,
+            UiTextFilter::Integer => unsigned.chars().all(|c| c.is_ascii_digit()),
+            UiTextFilter::Decimal => unsigned.chars().all(|c| c.is_ascii_digit() || c == '.') && unsigned.matches('.').count() <= 1,
+            UiTextFilter::Alphanumeric => value.chars().all(char::is_alphanumeric),
+            UiTextFilter::Custom(method) => method(value),
+        }
+    }
+}
+
+/// **Ui text input** - Component that turns [`UiText2dBundle`] into an editable text field.
+/// The input is edited while it has [`UiFocus`], clicking on it focuses it and it gets [`UiFocusable`] if it has none. It sends [`UiChangeEvent`]
+/// on every edit and [`UiSubmitEvent`] when `Enter` is pressed. The displayed [`Text`] is overwritten,
+/// with the style of its first section used for the value.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let input = UiTextInput::new().placeholder("Player name").max_length(16).filter(UiTextFilter::Alphanumeric);
+/// let password = UiTextInput::new().password('•');
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiTextInput {
+    /// The edited text
+    pub value: String,
+    /// Text shown while the value is empty
+    pub placeholder: String,
+    /// Maximum number of characters
+    pub max_length: Option<usize>,
+    /// Character shown instead of each character of the value
+    pub password: Option<char>,
+    /// Validation of the value
+    pub filter: UiTextFilter,
+    /// If the text is entered through IME composition instead of key presses
+    pub ime: bool,
+    /// Color of the placeholder text
+    pub placeholder_color: Color,
+    /// Color of the selected text
+    pub selection_color: Color,
+    /// Color of the caret
+    pub caret_color: Color,
+    /// Caret position in characters
+    pub (crate) caret: usize,
+    /// The other end of the selection in characters
+    pub (crate) anchor: usize,
+    /// Text being composed with IME
+    pub (crate) composition: String,
+    /// Time since the caret moved, for blinking
+    pub (crate) blink: f32,
+    /// Color of the value taken from the first section of [`Text`]
+    pub (crate) text_color: Option<Color>,
+}
+impl UiTextInput {
+    /// Creates new empty text input.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the value with a new value.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.set_value(value);
+        self
+    }
+    /// Replaces the placeholder with a new value.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+    /// Replaces the maximum length with a new value.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+    /// Replaces the password mask with a new value.
+    pub fn password(mut self, mask: char) -> Self {
+        self.password = Some(mask);
+        self
+    }
+    /// Replaces the filter with a new value.
+    pub fn filter(mut self, filter: UiTextFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+    /// Replaces the IME toggle with a new value.
+    pub fn ime(mut self, ime: bool) -> Self {
+        self.ime = ime;
+        self
+    }
+    /// Replaces the placeholder color with a new value.
+    pub fn placeholder_color(mut self, color: Color) -> Self {
+        self.placeholder_color = color;
+        self
+    }
+    /// Replaces the selection color with a new value.
+    pub fn selection_color(mut self, color: Color) -> Self {
+        self.selection_color = color;
+        self
+    }
+    /// Replaces the caret color with a new value.
+    pub fn caret_color(mut self, color: Color) -> Self {
+        self.caret_color = color;
+        self
+    }
+    /// Sets the value to a new value and moves the caret to its end.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.caret = self.len();
+        self.anchor = self.caret;
+    }
+
+    /// Returns the number of characters in the value.
+    pub fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+    /// Checks if the value is empty.
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+    /// Returns the caret position in characters.
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+    /// Returns the selected range in characters.
+    pub fn selection(&self) -> Range<usize> {
+        self.caret.min(self.anchor)..self.caret.max(self.anchor)
+    }
+    /// Returns the selected text.
+    pub fn selected_text(&self) -> String {
+        let selection = self.selection();
+        self.value.chars().skip(selection.start).take(selection.len()).collect()
+    }
+    /// Moves the caret to the position. Extending keeps the other end of the selection in place.
+    pub fn move_caret(&mut self, position: usize, extend: bool) {
+        self.caret = position.min(self.len());
+        if !extend { self.anchor = self.caret; }
+        self.blink = 0.0;
+    }
+    /// Selects the whole value.
+    pub fn select_all(&mut self) {
+        self.anchor = 0;
+        self.caret = self.len();
+    }
+    /// Replaces the selection with the text. Returns false if the filter rejected the edit.
+    pub fn insert(&mut self, text: &str) -> bool {
+        let selection = self.selection();
+        let available = self.max_length.map(|max| max.saturating_sub(self.len().saturating_sub(selection.len()))).unwrap_or(usize::MAX);
+        let text: String = text.chars().filter(|c| !c.is_control()).take(available).collect();
+        if text.is_empty() && selection.is_empty() { return false }
+        let caret = selection.start + text.chars().count();
+        self.replace(selection, &text, caret)
+    }
+    /// Deletes the selection or the character before the caret. Returns false if nothing changed.
+    pub fn delete_backward(&mut self) -> bool {
+        let selection = self.selection();
+        let range = if selection.is_empty() { self.caret.saturating_sub(1)..self.caret } else { selection };
+        if range.is_empty() { return false }
+        let caret = range.start;
+        self.replace(range, "", caret)
+    }
+    /// Deletes the selection or the character after the caret. Returns false if nothing changed.
+    pub fn delete_forward(&mut self) -> bool {
+        let selection = self.selection();
+        let range = if selection.is_empty() { self.caret..(self.caret + 1).min(self.len()) } else { selection };
+        if range.is_empty() { return false }
+        let caret = range.start;
+        self.replace(range, "", caret)
+    }
+
+    /// Returns the byte index of the character index.
+    fn byte(&self, index: usize) -> usize {
+        self.value.char_indices().nth(index).map(|(i, _)| i).unwrap_or(self.value.len())
+    }
+    /// Replaces the character range with the text if the filter accepts the result.
+    fn replace(&mut self, range: Range<usize>, text: &str, caret: usize) -> bool {
+        let mut value = self.value.clone();
+        value.replace_range(self.byte(range.start)..self.byte(range.end), text);
+        if !self.filter.accepts(&value) { return false }
+        self.value = value;
+        self.move_caret(caret, false);
+        true
+    }
+    /// Returns the value as displayed, masked if it is a password.
+    fn display(&self, range: Range<usize>) -> String {
+        let chars = self.value.chars().skip(range.start).take(range.len());
+        match self.password {
+            Some(mask) => chars.map(|_| mask).collect(),
+            None => chars.collect(),
+        }
+    }
+}
+impl Default for UiTextInput {
+    fn default() -> Self {
+        UiTextInput {
+            value: String::new(),
+            placeholder: String::new(),
+            max_length: None,
+            password: None,
+            filter: UiTextFilter::Any,
+            ime: false,
+            placeholder_color: Color::srgba(1.0, 1.0, 1.0, 0.4),
+            selection_color: Color::srgb(0.4, 0.6, 1.0),
+            caret_color: Color::WHITE,
+            caret: 0,
+            anchor: 0,
+            composition: String::new(),
+            blink: 0.0,
+            text_color: None,
+        }
+    }
+}
+
+
+// #========================#
+// #=== TEXT INPUT LOGIC ===#
+
+/// How long the caret stays visible or hidden while blinking.
+const CARET_BLINK: f32 = 0.5;
+
+/// This system makes new text inputs focusable, so they keep the focus given by clicking on them,
+/// and marks them with [`UiDragCapture`], so dragging over the text does not scroll.
+fn ui_text_input_added(mut commands: Commands, query: Query<(Entity, Has<UiFocusable>), Added<UiTextInput>>) {
+    for (entity, focusable) in &query {
+        commands.entity(entity).insert(UiDragCapture);
+        if !focusable { commands.entity(entity).insert(UiFocusable::new()); }
+    }
+}
+
+/// This system focuses text inputs that are pressed on.
+fn ui_text_input_focus(mut events: EventReader<Pointer<Down>>, mut focus: ResMut<UiFocus>, query: Query<(), With<UiTextInput>>) {
+    for event in events.read() {
+        if query.contains(event.target) && focus.focused != Some(event.target) {
+            focus.focused = Some(event.target);
+        }
+    }
+}
+
+/// This system edits the focused text input on keyboard and IME input.
+fn ui_text_input_edit(
+    mut keyboard: EventReader<KeyboardInput>,
+    mut ime: EventReader<Ime>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<UiFocus>,
+    mut clipboard: ResMut<UiClipboard>,
+    mut query: Query<&mut UiTextInput>,
+    mut change: EventWriter<UiChangeEvent>,
+    mut submit: EventWriter<UiSubmitEvent>,
+) {
+    let Some(entity) = focus.focused else { keyboard.clear(); ime.clear(); return };
+    let Ok(mut input) = query.get_mut(entity) else { keyboard.clear(); ime.clear(); return };
+    let control = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight, KeyCode::SuperLeft, KeyCode::SuperRight]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let mut changed = false;
+
+    for event in ime.read() {
+        match event {
+            Ime::Preedit { value, .. } => input.composition.clone_from(value),
+            Ime::Commit { value, .. } => {
+                input.composition.clear();
+                changed |= input.insert(value);
+            },
+            Ime::Disabled { .. } => input.composition.clear(),
+            _ => {},
+        }
+    }
+
+    for event in keyboard.read() {
+        if event.state != ButtonState::Pressed { continue }
+        let caret = input.caret();
+        match &event.logical_key {
+            Key::Enter => {
+                submit.send(UiSubmitEvent { target: entity, value: input.value.clone() });
+            },
+            Key::Escape => {
+                focus.focused = None;
+                break;
+            },
+            Key::Backspace => changed |= input.delete_backward(),
+            Key::Delete => changed |= input.delete_forward(),
+            Key::ArrowLeft => {
+                let selection = input.selection();
+                let position = if !shift && !selection.is_empty() { selection.start } else { caret.saturating_sub(1) };
+                input.move_caret(position, shift);
+            },
+            Key::ArrowRight => {
+                let selection = input.selection();
+                let position = if !shift && !selection.is_empty() { selection.end } else { caret + 1 };
+                input.move_caret(position, shift);
+            },
+            Key::Home => input.move_caret(0, shift),
+            Key::End => {
+                let end = input.len();
+                input.move_caret(end, shift);
+            },
+            Key::Character(character) if control => match character.to_lowercase().as_str() {
+                "a" => input.select_all(),
+                // Copying passwords would reveal them
+                "c" => if input.password.is_none() && !input.selection().is_empty() { clipboard.set(input.selected_text()) },
+                "x" => if input.password.is_none() && !input.selection().is_empty() {
+                    clipboard.set(input.selected_text());
+                    changed |= input.delete_backward();
+                },
+                "v" => if let Some(text) = clipboard.get() { changed |= input.insert(&text) },
+                _ => {},
+            },
+            Key::Character(character) if !input.ime => changed |= input.insert(character),
+            Key::Space if !input.ime => changed |= input.insert(" "),
+            _ => {},
+        }
+    }
+
+    if changed {
+        change.send(UiChangeEvent { target: entity, value: input.value.clone() });
+    }
+}
+
+/// This system turns IME on for the focused text input and places the IME popup over it.
+fn ui_text_input_ime(
+    focus: Res<UiFocus>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    query: Query<(&UiTextInput, &GlobalTransform)>,
+) {
+    let Ok(mut window) = windows.get_single_mut() else { return };
+    let focused = focus.focused.and_then(|entity| query.get(entity).ok()).filter(|(input, _)| input.ime);
+    if window.ime_enabled != focused.is_some() { window.ime_enabled = focused.is_some(); }
+    let Some((_, transform)) = focused else { return };
+    let Some((camera, camera_transform)) = cameras.iter().find(|(camera, _)| camera.is_active) else { return };
+    if let Some(position) = camera.world_to_viewport(camera_transform, transform.translation()) {
+        if window.ime_position != position { window.ime_position = position; }
+    }
+}
+
+/// This system writes the value, caret, selection and placeholder of text inputs into their [`Text`].
+fn ui_text_input_display(
+    time: Res<Time>,
+    focus: Res<UiFocus>,
+    mut query: Query<(Entity, &mut UiTextInput, &mut Text)>,
+) {
+    for (entity, mut input, mut text) in &mut query {
+        let focused = focus.focused == Some(entity);
+        let Some(style) = text.sections.first().map(|section| section.style.clone()) else { continue };
+        let input = input.bypass_change_detection();
+        input.blink += time.delta_seconds();
+        let caret_visible = focused && (input.blink / CARET_BLINK) as u32 % 2 == 0;
+        let color = *input.text_color.get_or_insert(style.color);
+
+        let section = |value: String, color: Color| TextSection { value, style: TextStyle { color, ..style.clone() } };
+        let caret = section("|".into(), if caret_visible { input.caret_color } else { Color::NONE });
+
+        let sections = if input.value.is_empty() && input.composition.is_empty() {
+            vec![caret, section(input.placeholder.clone(), input.placeholder_color)]
+        } else {
+            let selection = input.selection();
+            let mut sections = vec![
+                section(input.display(0..selection.start), color),
+                section(input.display(selection.clone()), input.selection_color),
+                section(input.display(selection.end..input.len()), color),
+            ];
+            // The caret and IME composition are placed on the moving end of the selection
+            let index = if input.caret == selection.start { 1 } else { 2 };
+            sections.insert(index, caret);
+            if !input.composition.is_empty() {
+                sections.insert(index, section(input.composition.clone(), color));
+            }
+            sections
+        };
+
+        let same = text.sections.len() == sections.len() && text.sections.iter().zip(&sections).all(|(a, b)| a.value == b.value && a.style.color == b.style.color);
+        if !same { text.sections = sections; }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct TextInputPlugin;
+impl Plugin for TextInputPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<UiSubmitEvent>()
+            .init_resource::<UiClipboard>()
+            .add_systems(Update, (
+                ui_text_input_added,
+                ui_text_input_focus,
+                ui_text_input_edit,
+                ui_text_input_ime,
+                ui_text_input_display,
+            ).chain().before(UiSystems::Send));
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use bevy::render::camera::NormalizedRenderTarget;
+    use bevy_mod_picking::{backend::HitData, pointer::{Location, PointerButton, PointerId}};
+    use crate::*;
+    use super::{ui_text_input_added, ui_text_input_focus};
+
+    #[test]
+    fn insert() {
+        let mut input = UiTextInput::new().value("hello");
+        assert!(input.insert(" world"));
+        assert_eq!(input.value, "hello world");
+        assert_eq!(input.caret(), 11);
+
+        // Replaces the selection
+        input.move_caret(0, false);
+        input.move_caret(5, true);
+        assert_eq!(input.selected_text(), "hello");
+        assert!(input.insert("bye"));
+        assert_eq!(input.value, "bye world");
+        assert_eq!(input.caret(), 3);
+
+        // Control characters are dropped
+        assert!(!input.insert("\n"));
+        assert_eq!(input.value, "bye world");
+    }
+
+    #[test]
+    fn insert_unicode() {
+        let mut input = UiTextInput::new().value("žluť");
+        input.move_caret(2, false);
+        assert!(input.insert("ou"));
+        assert_eq!(input.value, "žlouuť");
+        assert_eq!(input.len(), 6);
+        assert_eq!(input.caret(), 4);
+    }
+
+    #[test]
+    fn max_length() {
+        let mut input = UiTextInput::new().max_length(5).value("abc");
+        assert!(input.insert("defgh"));
+        assert_eq!(input.value, "abcde");
+        assert!(!input.insert("f"));
+
+        // The selection is replaced, so it does not count
+        input.select_all();
+        assert!(input.insert("12345678"));
+        assert_eq!(input.value, "12345");
+    }
+
+    #[test]
+    fn delete() {
+        let mut input = UiTextInput::new().value("abcd");
+        assert!(input.delete_backward());
+        assert_eq!(input.value, "abc");
+        assert!(!input.delete_forward());
+
+        input.move_caret(0, false);
+        assert!(!input.delete_backward());
+        assert!(input.delete_forward());
+        assert_eq!(input.value, "bc");
+
+        // Deletes the selection
+        input.select_all();
+        assert!(input.delete_backward());
+        assert!(input.is_empty());
+        assert_eq!(input.caret(), 0);
+    }
+
+    #[test]
+    fn filter() {
+        assert!(UiTextFilter::Integer.accepts("-12"));
+        assert!(UiTextFilter::Integer.accepts("-"));
+        assert!(!UiTextFilter::Integer.accepts("1.5"));
+        assert!(UiTextFilter::Decimal.accepts("-1."));
+        assert!(!UiTextFilter::Decimal.accepts("1.2.3"));
+        assert!(!UiTextFilter::Alphanumeric.accepts("a b"));
+        assert!(UiTextFilter::Custom(|value| value.len() < 3).accepts("ab"));
+
+        // Rejected edits leave the value unchanged
+        let mut input = UiTextInput::new().filter(UiTextFilter::Integer).value("12");
+        assert!(!input.insert("a"));
+        assert!(input.insert("3"));
+        input.move_caret(1, false);
+        assert!(!input.insert("-"));
+        assert_eq!(input.value, "123");
+        assert_eq!(input.caret(), 1);
+    }
+
+    #[test]
+    fn password() {
+        let input = UiTextInput::new().password('•').value("secret");
+        assert_eq!(input.display(0..input.len()), "••••••");
+        assert_eq!(input.display(1..3), "••");
+        assert_eq!(input.value, "secret");
+    }
+
+    /// Creates app with the focus systems and a visible focusable button.
+    fn app() -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<UiFocus>()
+            .init_resource::<UiModalStack>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<GamepadButton>>()
+            .init_resource::<Gamepads>()
+            .add_event::<Pointer<Down>>()
+            .add_event::<UiClickEvent>()
+            .add_systems(Update, (ui_text_input_added, ui_text_input_focus, ui_focus_navigation).chain());
+        let button = spawn(&mut app, Vec3::new(0.0, -100.0, 0.0), UiFocusable::new().order(1));
+        (app, button)
+    }
+
+    /// Spawns visible node at the position with the components.
+    fn spawn(app: &mut App, position: Vec3, components: impl Bundle) -> Entity {
+        let mut visibility = ViewVisibility::default();
+        visibility.set();
+        app.world_mut().spawn((components, Dimension::default(), GlobalTransform::from_translation(position), visibility)).id()
+    }
+
+    /// Presses the entity with the mouse.
+    fn click(app: &mut App, target: Entity) {
+        let location = Location { target: NormalizedRenderTarget::Image(Handle::default()), position: Vec2::ZERO };
+        let down = Down { button: PointerButton::Primary, hit: HitData::new(Entity::PLACEHOLDER, 0.0, None, None) };
+        app.world_mut().send_event(Pointer::new(PointerId::Mouse, location, target, down));
+    }
+
+    /// Presses the key for one update.
+    fn press(app: &mut App, key: KeyCode) {
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(key);
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().release(key);
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().clear();
+    }
+
+    #[test]
+    fn click_focus() {
+        let (mut app, _) = app();
+        let input = spawn(&mut app, Vec3::ZERO, UiTextInput::new());
+        app.update();
+        assert!(app.world().get::<UiFocusable>(input).is_some());
+        assert!(app.world().get::<UiDragCapture>(input).is_some());
+
+        click(&mut app, input);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<UiFocus>().focused, Some(input));
+    }
+
+    #[test]
+    fn tab_from_input() {
+        let (mut app, button) = app();
+        let input = spawn(&mut app, Vec3::ZERO, (UiTextInput::new(), UiFocusable::new().order(0)));
+        app.update();
+        click(&mut app, input);
+        app.update();
+
+        // Arrows are used for editing, the button below is not focused
+        press(&mut app, KeyCode::ArrowDown);
+        assert_eq!(app.world().resource::<UiFocus>().focused, Some(input));
+
+        // Tab moves the focus both ways
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.world().resource::<UiFocus>().focused, Some(button));
+        app.world_mut().resource_mut::<UiFocus>().focused = Some(input);
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::ShiftLeft);
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.world().resource::<UiFocus>().focused, Some(button));
+    }
+}
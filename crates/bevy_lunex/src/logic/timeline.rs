@@ -0,0 +1,351 @@
+use crate::*;
+
+
+// #==============#
+// #=== EVENTS ===#
+
+/// This event is sent when [`UiTimeline`] completes. Looping timelines send it at the end of every cycle.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiTimelineFinished {
+    /// The entity with the timeline
+    pub target: Entity,
+}
+
+
+// #========================#
+// #=== TIMELINE STRUCTS ===#
+
+/// A single keyframe of [`UiTimeline`]. Colors and depth biases set to `None` keep the value of the previous keyframe,
+/// layouts set to `None` use the base layout.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let keyframe = UiKeyframe::new(0.5).layout(UiLayout::window().size(Rl(50.0))).color(Color::WHITE).ease(UiEase::Cubic(EaseMode::Out));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiKeyframe {
+    /// Time of the keyframe in seconds since the timeline started, excluding the delay
+    pub time: f32,
+    /// The layout at this keyframe, `None` means the base layout
+    pub layout: Option<Layout>,
+    /// The color at this keyframe
+    pub color: Option<Color>,
+    /// The depth bias at this keyframe
+    pub depth_bias: Option<f32>,
+    /// The easing curve used to get to this keyframe from the previous one
+    pub ease: UiEase,
+}
+impl UiKeyframe {
+    /// Creates new keyframe at the time.
+    pub fn new(time: f32) -> Self {
+        UiKeyframe { time, layout: None, color: None, depth_bias: None, ease: UiEase::Linear }
+    }
+    /// Replaces the layout with a new value.
+    pub fn layout(mut self, layout: impl Into<Layout>) -> Self {
+        self.layout = Some(layout.into());
+        self
+    }
+    /// Replaces the color with a new value.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+    /// Replaces the depth bias with a new value.
+    pub fn depth_bias(mut self, depth_bias: f32) -> Self {
+        self.depth_bias = Some(depth_bias);
+        self
+    }
+    /// Replaces the easing curve with a new value.
+    pub fn ease(mut self, ease: UiEase) -> Self {
+        self.ease = ease;
+        self
+    }
+}
+
+/// What happens when [`UiTimeline`] reaches its last keyframe.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UiTimelineRepeat {
+    /// The timeline stops at the last keyframe.
+    #[default]
+    Once,
+    /// The timeline starts again from the first keyframe.
+    Loop,
+    /// The timeline plays backwards to the first keyframe and then starts again.
+    PingPong,
+}
+
+/// **Ui timeline** - Component that sequences keyframed layouts, colors and depth biases over time.
+/// Keyframe layouts are sent to the node as [`TimelineFrom`] and [`TimelineTo`] layouts and tweened
+/// with [`UiLayoutController`], so it must be present on the entity. Animator layouts are still blended on top.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let pulse = UiTimeline::new()
+///     .keyframe(UiKeyframe::new(0.0))
+///     .keyframe(UiKeyframe::new(0.5).layout(UiLayout::window_full().size(Rl(110.0))).ease(UiEase::Quad(EaseMode::InOut)))
+///     .repeat(UiTimelineRepeat::PingPong);
+/// ```
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct UiTimeline {
+    /// Keyframes sorted by time
+    pub keyframes: Vec<UiKeyframe>,
+    /// Time in seconds before the first keyframe starts
+    pub delay: f32,
+    /// What happens after the last keyframe
+    pub repeat: UiTimelineRepeat,
+    /// Setting this to true will stop the timeline at its current time
+    pub paused: bool,
+    /// Time in seconds since the timeline started
+    pub (crate) elapsed: f32,
+    /// If the timeline completed
+    pub (crate) finished: bool,
+    /// The last color sent to the entity
+    pub (crate) color: Option<Color>,
+}
+impl UiTimeline {
+    /// Creates new empty timeline.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Adds new keyframe, keeping the keyframes sorted by time.
+    pub fn keyframe(mut self, keyframe: UiKeyframe) -> Self {
+        self.add_keyframe(keyframe);
+        self
+    }
+    /// Replaces the delay with a new value.
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+    /// Replaces the repeat mode with a new value.
+    pub fn repeat(mut self, repeat: UiTimelineRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+    /// Adds new keyframe, keeping the keyframes sorted by time.
+    pub fn add_keyframe(&mut self, keyframe: UiKeyframe) {
+        let index = self.keyframes.partition_point(|k| k.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+    }
+    /// Sets the delay to a new value.
+    pub fn set_delay(&mut self, delay: f32) {
+        self.delay = delay;
+    }
+    /// Sets the repeat mode to a new value.
+    pub fn set_repeat(&mut self, repeat: UiTimelineRepeat) {
+        self.repeat = repeat;
+    }
+    /// Plays the timeline again from the start, including the delay.
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+        self.finished = false;
+        self.paused = false;
+        self.color = None;
+    }
+    /// Returns the time in seconds of one pass from the first to the last keyframe.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map(|k| k.time).unwrap_or(0.0)
+    }
+    /// Checks if the timeline completed. Looping timelines never complete.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the time on the keyframes for the elapsed time.
+    fn local_time(&self) -> f32 {
+        let duration = self.duration();
+        let time = (self.elapsed - self.delay).max(0.0);
+        if duration <= 0.0 { return duration }
+        match self.repeat {
+            UiTimelineRepeat::Once => time.min(duration),
+            UiTimelineRepeat::Loop => time % duration,
+            UiTimelineRepeat::PingPong => {
+                let time = time % (2.0 * duration);
+                if time > duration { 2.0 * duration - time } else { time }
+            },
+        }
+    }
+    /// Returns the time in seconds of one cycle.
+    fn cycle(&self) -> f32 {
+        if self.repeat == UiTimelineRepeat::PingPong { 2.0 * self.duration() } else { self.duration() }
+    }
+    /// Returns the number of cycles completed at the elapsed time. Timelines without duration complete once the delay passes.
+    fn completed(&self, elapsed: f32) -> u32 {
+        let time = elapsed - self.delay;
+        let cycle = self.cycle();
+        if time <= 0.0 { 0 } else if cycle <= 0.0 { 1 } else { (time / cycle) as u32 }
+    }
+    /// Advances the elapsed time and returns the number of cycles completed on the way.
+    /// Completing a [`UiTimelineRepeat::Once`] timeline finishes it.
+    fn advance(&mut self, delta: f32) -> u32 {
+        let previous = self.completed(self.elapsed);
+        self.elapsed += delta;
+        let cycles = self.completed(self.elapsed).saturating_sub(previous);
+        if cycles > 0 && self.repeat == UiTimelineRepeat::Once {
+            self.finished = true;
+            return 1;
+        }
+        cycles
+    }
+    /// Returns the indexes of the two surrounding keyframes and the eased tween between them.
+    fn sample(&self) -> Option<(usize, usize, f32)> {
+        if self.keyframes.is_empty() { return None }
+        let time = self.local_time();
+        let next = self.keyframes.partition_point(|k| k.time <= time);
+        if next == 0 { return Some((0, 0, 0.0)) }
+        if next == self.keyframes.len() { return Some((next - 1, next - 1, 0.0)) }
+        let (from, to) = (&self.keyframes[next - 1], &self.keyframes[next]);
+        let tween = (time - from.time) / (to.time - from.time);
+        Some((next - 1, next, to.ease.ease(tween)))
+    }
+    /// Returns the last value of the property set at or before the keyframe.
+    fn held<V: Copy>(&self, index: usize, property: impl Fn(&UiKeyframe) -> Option<V>) -> Option<V> {
+        self.keyframes[..=index].iter().rev().find_map(property)
+    }
+}
+
+/// This system advances [`UiTimeline`] components and sends their keyframe layouts, colors and depth biases.
+fn ui_timeline_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut UiTimeline, &mut UiLayoutController, Option<&UiLayout<TimelineFrom>>, Option<&UiLayout<TimelineTo>>, Option<&mut UiDepthBias>)>,
+    mut set_color: EventWriter<actions::SetColor>,
+    mut finished: EventWriter<UiTimelineFinished>,
+) {
+    for (entity, mut timeline, mut controller, from_layout, to_layout, depth_bias) in &mut query {
+        if timeline.paused || timeline.finished { continue }
+
+        // Advance the time and send an event for each completed cycle
+        for _ in 0..timeline.advance(time.delta_seconds()) {
+            finished.send(UiTimelineFinished { target: entity });
+        }
+
+        let Some((from, to, tween)) = timeline.sample() else { continue };
+
+        // Send the keyframe layouts only when they change
+        let from_index = match timeline.keyframes[from].layout {
+            Some(layout) => {
+                if from_layout.map(|l| l.layout) != Some(layout) { commands.entity(entity).insert(UiLayout::<TimelineFrom>::from(layout)); }
+                TimelineFrom::INDEX
+            },
+            None => Base::INDEX,
+        };
+        let to_index = match timeline.keyframes[to].layout {
+            Some(layout) => {
+                if to_layout.map(|l| l.layout) != Some(layout) { commands.entity(entity).insert(UiLayout::<TimelineTo>::from(layout)); }
+                TimelineTo::INDEX
+            },
+            None => Base::INDEX,
+        };
+        if controller.index != [from_index, to_index] || controller.tween != tween {
+            controller.index = [from_index, to_index];
+            controller.tween = tween;
+        }
+
+        // Tween the colors and depth biases, missing values are held from previous keyframes
+        let color_from = timeline.held(from, |k| k.color);
+        let color_to = timeline.held(to, |k| k.color);
+        if let Some(color) = match (color_from, color_to) {
+            (Some(a), Some(b)) => Some(a.lerp(b, tween)),
+            (a, b) => a.or(b),
+        } {
+            if timeline.color != Some(color) {
+                timeline.color = Some(color);
+                set_color.send(actions::SetColor { target: entity, color });
+            }
+        }
+
+        let bias_from = timeline.held(from, |k| k.depth_bias);
+        let bias_to = timeline.held(to, |k| k.depth_bias);
+        if let Some(bias) = match (bias_from, bias_to) {
+            (Some(a), Some(b)) => Some(a + (b - a) * tween),
+            (a, b) => a.or(b),
+        } {
+            match depth_bias {
+                Some(mut depth_bias) => if depth_bias.0 != bias { depth_bias.0 = bias },
+                None => { commands.entity(entity).insert(UiDepthBias(bias)); },
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct TimelinePlugin;
+impl Plugin for TimelinePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<UiTimelineFinished>()
+            .add_systems(Update, ui_timeline_system.before(UiSystems::Send));
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{UiKeyframe, UiTimeline, UiTimelineRepeat};
+
+    fn timeline(repeat: UiTimelineRepeat) -> UiTimeline {
+        UiTimeline::new().keyframe(UiKeyframe::new(0.0)).keyframe(UiKeyframe::new(1.0)).repeat(repeat)
+    }
+
+    #[test]
+    fn delay() {
+        let mut timeline = timeline(UiTimelineRepeat::Once).delay(0.5);
+        assert_eq!(timeline.advance(0.25), 0);
+        assert_eq!(timeline.local_time(), 0.0);
+        assert_eq!(timeline.sample(), Some((0, 1, 0.0)));
+
+        assert_eq!(timeline.advance(0.5), 0);
+        assert_eq!(timeline.sample(), Some((0, 1, 0.25)));
+        assert!(!timeline.is_finished());
+
+        assert_eq!(timeline.advance(1.0), 1);
+        assert!(timeline.is_finished());
+        assert_eq!(timeline.local_time(), 1.0);
+        assert_eq!(timeline.sample(), Some((1, 1, 0.0)));
+    }
+
+    #[test]
+    fn loop_cycles() {
+        let mut timeline = timeline(UiTimelineRepeat::Loop);
+        assert_eq!(timeline.advance(0.5), 0);
+        assert_eq!(timeline.advance(0.75), 1);
+        assert_eq!(timeline.local_time(), 0.25);
+
+        // One event for each cycle completed in a long frame
+        assert_eq!(timeline.advance(2.0), 2);
+        assert_eq!(timeline.local_time(), 0.25);
+        assert!(!timeline.is_finished());
+    }
+
+    #[test]
+    fn ping_pong() {
+        let mut timeline = timeline(UiTimelineRepeat::PingPong);
+        assert_eq!(timeline.advance(0.75), 0);
+        assert_eq!(timeline.local_time(), 0.75);
+
+        // Turns around at the last keyframe and completes the cycle back at the first one
+        assert_eq!(timeline.advance(0.5), 0);
+        assert_eq!(timeline.local_time(), 0.75);
+        assert_eq!(timeline.sample(), Some((0, 1, 0.75)));
+        assert_eq!(timeline.advance(0.75), 1);
+        assert_eq!(timeline.local_time(), 0.0);
+    }
+
+    #[test]
+    fn single_keyframe() {
+        let mut timeline = UiTimeline::new().keyframe(UiKeyframe::new(0.0));
+        assert_eq!(timeline.advance(0.0), 0);
+        assert_eq!(timeline.advance(0.016), 1);
+        assert!(timeline.is_finished());
+        assert_eq!(timeline.sample(), Some((0, 0, 0.0)));
+
+        // Restarting plays it again
+        timeline.restart();
+        assert!(!timeline.is_finished());
+        assert_eq!(timeline.advance(0.016), 1);
+    }
+}
@@ -0,0 +1,376 @@
+use std::sync::Arc;
+use bevy::window::PrimaryWindow;
+
+use crate::*;
+
+
+// #=======================#
+// #=== TOOLTIP STRUCTS ===#
+
+/// Callback spawning the content of the tooltip, it is called with the path of the tooltip node.
+pub type UiTooltipBuilder = Arc<dyn Fn(&mut ChildBuilder, &str) + Send + Sync>;
+
+/// Side of the hovered node the tooltip is placed on. If it does not fit inside the [`UiTree`],
+/// it is flipped to the opposite side and shifted along the side to stay inside.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum UiTooltipPlacement {
+    Above,
+    #[default]
+    Below,
+    Left,
+    Right,
+}
+
+/// **Ui tooltip** - Component showing a floating node next to the entity after the pointer hovers over it for the delay.
+/// The tooltip node is spawned at the top level of the [`UiTree`] under an unique path, the builder is then called
+/// with the path to spawn the content of the tooltip as its subnodes. The tooltip fades in with [`UiAnimator<Intro>`]
+/// and out with [`UiAnimator<Outro>`], changing the alpha of the [`Sprite`] and [`Text`] content.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let tooltip = UiTooltip::new(Vec2::new(160.0, 40.0), |ui, path| {
+///     ui.spawn((
+///         UiLink::<MainUi>::path(format!("{path}/Background")),
+///         UiLayout::window_full().pack::<Base>(),
+///         UiImage2dBundle::default(),
+///     ));
+/// }).delay(0.3).placement(UiTooltipPlacement::Right);
+/// ```
+#[derive(Component, Clone)]
+pub struct UiTooltip {
+    /// Seconds the pointer has to hover over the entity before the tooltip is shown
+    pub delay: f32,
+    /// Preferred side of the hovered node
+    pub placement: UiTooltipPlacement,
+    /// If the tooltip is placed next to the pointer instead of the hovered node
+    pub follow_cursor: bool,
+    /// Distance between the tooltip and the hovered node in [`Ab`] units
+    pub gap: f32,
+    /// Size of the tooltip in [`Ab`] units
+    pub size: Vec2,
+    /// Duration of the fade in and out in seconds
+    pub fade: f32,
+    /// Depth bias of the tooltip and its content, so it is placed on top of other nodes
+    pub depth: f32,
+    /// Function spawning the content, it is called with the path of the tooltip node
+    pub builder: UiTooltipBuilder,
+    /// How long the entity is hovered
+    pub (crate) hovered: Option<f32>,
+    /// The spawned tooltip node
+    pub (crate) node: Option<Entity>,
+}
+impl UiTooltip {
+    /// Creates new tooltip with the size and the content builder.
+    pub fn new(size: Vec2, builder: impl Fn(&mut ChildBuilder, &str) + Send + Sync + 'static) -> Self {
+        UiTooltip {
+            delay: 0.5,
+            placement: UiTooltipPlacement::Below,
+            follow_cursor: false,
+            gap: 8.0,
+            size,
+            fade: 0.15,
+            depth: 100.0,
+            builder: Arc::new(builder),
+            hovered: None,
+            node: None,
+        }
+    }
+    /// Replaces the delay with a new value.
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+    /// Replaces the placement with a new value.
+    pub fn placement(mut self, placement: UiTooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+    /// Replaces the follow cursor flag with a new value.
+    pub fn follow_cursor(mut self, follow_cursor: bool) -> Self {
+        self.follow_cursor = follow_cursor;
+        self
+    }
+    /// Replaces the gap with a new value.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+    /// Replaces the fade duration with a new value.
+    pub fn fade(mut self, fade: f32) -> Self {
+        self.fade = fade;
+        self
+    }
+    /// Replaces the depth bias with a new value.
+    pub fn depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+    /// Returns if the tooltip is shown.
+    pub fn is_shown(&self) -> bool {
+        self.node.is_some()
+    }
+}
+impl std::fmt::Debug for UiTooltip {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UiTooltip")
+            .field("delay", &self.delay)
+            .field("placement", &self.placement)
+            .field("follow_cursor", &self.follow_cursor)
+            .field("gap", &self.gap)
+            .field("size", &self.size)
+            .field("fade", &self.fade)
+            .field("depth", &self.depth)
+            .field("hovered", &self.hovered)
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+/// This struct marks the tooltip node spawned by [`UiTooltip`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiTooltipNode {
+    /// The hovered entity
+    pub source: Entity,
+}
+
+/// This struct marks the content of a tooltip node and holds its original alpha values.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub (crate) struct UiTooltipPart {
+    /// Alpha of the sprite followed by alphas of the text sections
+    alphas: Vec<f32>,
+}
+
+
+// #=====================#
+// #=== TOOLTIP LOGIC ===#
+
+/// Returns the top left corner of the tooltip placed next to the anchor, kept inside the bounds.
+pub (crate) fn tooltip_position(anchor: Rect, size: Vec2, placement: UiTooltipPlacement, gap: f32, bounds: Rect) -> Vec2 {
+    let center = anchor.center() - size / 2.0;
+    let position = |placement| match placement {
+        UiTooltipPlacement::Above => Vec2::new(center.x, anchor.min.y - gap - size.y),
+        UiTooltipPlacement::Below => Vec2::new(center.x, anchor.max.y + gap),
+        UiTooltipPlacement::Left => Vec2::new(anchor.min.x - gap - size.x, center.y),
+        UiTooltipPlacement::Right => Vec2::new(anchor.max.x + gap, center.y),
+    };
+    let fits = |placement, position: Vec2| match placement {
+        UiTooltipPlacement::Above => position.y >= bounds.min.y,
+        UiTooltipPlacement::Below => position.y + size.y <= bounds.max.y,
+        UiTooltipPlacement::Left => position.x >= bounds.min.x,
+        UiTooltipPlacement::Right => position.x + size.x <= bounds.max.x,
+    };
+    let opposite = match placement {
+        UiTooltipPlacement::Above => UiTooltipPlacement::Below,
+        UiTooltipPlacement::Below => UiTooltipPlacement::Above,
+        UiTooltipPlacement::Left => UiTooltipPlacement::Right,
+        UiTooltipPlacement::Right => UiTooltipPlacement::Left,
+    };
+
+    // Flip to the opposite side only if it fits there
+    let mut pos = position(placement);
+    if !fits(placement, pos) && fits(opposite, position(opposite)) {
+        pos = position(opposite);
+    }
+
+    // Shift to stay inside
+    pos.clamp(bounds.min, (bounds.max - size).max(bounds.min))
+}
+
+/// This system tracks how long [`UiTooltip`] entities are hovered. Pressing the entity hides the tooltip until it is hovered again.
+fn ui_tooltip_hover_system(
+    mut over: EventReader<Pointer<Over>>,
+    mut out: EventReader<Pointer<Out>>,
+    mut down: EventReader<Pointer<Down>>,
+    mut query: Query<&mut UiTooltip>,
+) {
+    for event in over.read() {
+        if let Ok(mut tooltip) = query.get_mut(event.target) { tooltip.hovered = Some(0.0); }
+    }
+    for event in out.read().map(|event| event.target).chain(down.read().map(|event| event.target)) {
+        if let Ok(mut tooltip) = query.get_mut(event) { tooltip.hovered = None; }
+    }
+}
+
+/// This system spawns, places and hides the tooltip nodes of [`UiTooltip`] entities.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn ui_tooltip_system<T: Component, N: Default + Component>(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut counter: Local<usize>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    pointers: Query<&PointerLocation>,
+    uis: Query<(&UiTree<T, N>, &Dimension, Option<&SourceFromCamera>)>,
+    mut sources: Query<(Entity, &mut UiTooltip, &UiLink<T>, &Parent, &Dimension, Has<Element>, &GlobalTransform)>,
+    mut roots: Query<(Entity, &UiTooltipNode, &mut UiLayout), (With<UiLink<T>>, Without<UiDespawnWithOutro>)>,
+) {
+    for (entity, mut tooltip, link, parent, dimension, element, transform) in &mut sources {
+        let tooltip = tooltip.bypass_change_detection();
+        if let Some(hovered) = &mut tooltip.hovered { *hovered += time.delta_seconds(); }
+        if tooltip.hovered.map(|hovered| hovered < tooltip.delay).unwrap_or(true) {
+            tooltip.node = None;
+            continue;
+        }
+        let Some((mut anchor, abs_scale, bounds)) = uis.get(parent.get()).ok().and_then(|ui| picking::tree_rect(&window, ui, &link.path)) else { continue };
+
+        // Place next to the hovered node or the pointer over it
+        if tooltip.follow_cursor {
+            let cursor = pointers.iter().filter_map(|pointer| pointer.location()).find_map(|location| {
+                picking::pointer_to_tree(location, &cameras, &primary_window, anchor, dimension, element, transform).filter(|point| anchor.contains(*point))
+            });
+            if let Some(point) = cursor { anchor = Rect::from_center_size(point, Vec2::ZERO); }
+        }
+        let position = tooltip_position(anchor, tooltip.size * abs_scale, tooltip.placement, tooltip.gap * abs_scale, bounds);
+        let layout = UiLayout::window().pos(Ab(position / abs_scale)).size(Ab(tooltip.size)).pack::<Base>();
+
+        match tooltip.node.and_then(|root| roots.get_mut(root).ok()) {
+            Some((.., mut root_layout)) => if root_layout.layout != layout.layout { root_layout.layout = layout.layout; },
+            None => {
+                let path = format!("Tooltip {}", *counter);
+                *counter += 1;
+
+                // Start from the faded out intro state right away
+                let speed = 1.0 / tooltip.fade.max(f32::EPSILON);
+                let mut intro = UiAnimator::<Intro>::new().forward_speed(speed).backward_speed(speed);
+                intro.animation_transition = 1.0;
+                intro.animation_direction = -1.0;
+                intro.eased_direction = -1.0;
+
+                let builder = tooltip.builder.clone();
+                let mut root = Entity::PLACEHOLDER;
+                commands.entity(parent.get()).with_children(|ui| {
+                    root = ui.spawn((
+                        UiNodeBundle::<T> { link: UiLink::<T>::path(&path), layout },
+                        UiTooltipNode { source: entity },
+                        UiDepthBias(tooltip.depth),
+                        intro,
+                        UiAnimator::<Outro>::new().forward_speed(speed),
+                    )).id();
+                    builder(ui, &path);
+                });
+                tooltip.node = Some(root);
+            },
+        }
+    }
+
+    // Fade out tooltips of removed or no longer hovered entities
+    for (root, node, _) in &roots {
+        let shown = sources.get(node.source).map(|(_, tooltip, ..)| tooltip.node == Some(root)).unwrap_or(false);
+        if !shown { commands.entity(root).despawn_with_outro(); }
+    }
+}
+
+/// This system fades the content of tooltip nodes while they animate and places it on top of other nodes.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn ui_tooltip_fade_system<T: Component>(
+    mut commands: Commands,
+    roots: Query<(Entity, &UiAnimator<Intro>, &UiAnimator<Outro>, Option<&UiDepthBias>), (With<UiTooltipNode>, Or<(Changed<UiAnimator<Intro>>, Changed<UiAnimator<Outro>>)>)>,
+    links: Query<(Entity, &UiLink<T>, &Parent)>,
+    mut query: Query<(Option<&mut Sprite>, Option<&mut Text>, Option<&UiTooltipPart>, Option<&UiDepthBias>), Without<UiTooltipNode>>,
+) {
+    for (root, intro, outro, root_bias) in &roots {
+        let opacity = (1.0 - intro.value()) * (1.0 - outro.value());
+
+        for entity in linked_subnodes(root, &links) {
+            let Ok((mut sprite, mut text, part, bias)) = query.get_mut(entity) else { continue };
+
+            // Remember the original alphas of new content
+            let alphas = match part {
+                Some(part) => part.alphas.clone(),
+                None => {
+                    let alphas: Vec<f32> = sprite.as_deref().map(|sprite| sprite.color.alpha()).into_iter()
+                        .chain(text.as_deref().into_iter().flat_map(|text| text.sections.iter().map(|section| section.style.color.alpha())))
+                        .collect();
+                    let depth = root_bias.map(|bias| bias.0).unwrap_or(0.0) + bias.map(|bias| bias.0).unwrap_or(0.0);
+                    commands.entity(entity).insert((UiTooltipPart { alphas: alphas.clone() }, UiDepthBias(depth), Pickable::IGNORE));
+                    alphas
+                },
+            };
+
+            let mut alphas = alphas.into_iter().map(|alpha| alpha * opacity);
+            if let Some(sprite) = &mut sprite {
+                let alpha = alphas.next().unwrap_or(opacity);
+                if sprite.color.alpha() != alpha { sprite.color = sprite.color.with_alpha(alpha); }
+            }
+            if let Some(text) = &mut text {
+                let alphas: Vec<f32> = alphas.collect();
+                if text.sections.iter().zip(&alphas).any(|(section, alpha)| section.style.color.alpha() != *alpha) {
+                    for (section, alpha) in text.sections.iter_mut().zip(alphas) {
+                        section.style.color = section.style.color.with_alpha(alpha);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct TooltipPlugin;
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, ui_tooltip_hover_system);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use bevy::math::{Rect, Vec2};
+    use super::{tooltip_position, UiTooltipPlacement};
+
+    const BOUNDS: Rect = Rect { min: Vec2::ZERO, max: Vec2::new(200.0, 100.0) };
+    const SIZE: Vec2 = Vec2::new(40.0, 20.0);
+
+    #[test]
+    fn placement() {
+        let anchor = Rect::new(80.0, 40.0, 120.0, 60.0);
+        assert_eq!(tooltip_position(anchor, SIZE, UiTooltipPlacement::Above, 5.0, BOUNDS), Vec2::new(80.0, 15.0));
+        assert_eq!(tooltip_position(anchor, SIZE, UiTooltipPlacement::Below, 5.0, BOUNDS), Vec2::new(80.0, 65.0));
+        assert_eq!(tooltip_position(anchor, SIZE, UiTooltipPlacement::Left, 5.0, BOUNDS), Vec2::new(35.0, 40.0));
+        assert_eq!(tooltip_position(anchor, SIZE, UiTooltipPlacement::Right, 5.0, BOUNDS), Vec2::new(125.0, 40.0));
+    }
+
+    #[test]
+    fn flip() {
+        // Does not fit below, flips above
+        let anchor = Rect::new(80.0, 70.0, 120.0, 90.0);
+        assert_eq!(tooltip_position(anchor, SIZE, UiTooltipPlacement::Below, 5.0, BOUNDS), Vec2::new(80.0, 45.0));
+
+        // Does not fit left, flips right
+        let anchor = Rect::new(10.0, 40.0, 30.0, 60.0);
+        assert_eq!(tooltip_position(anchor, SIZE, UiTooltipPlacement::Left, 5.0, BOUNDS), Vec2::new(35.0, 40.0));
+    }
+
+    #[test]
+    fn shift() {
+        // Fits on neither side, stays on the requested side clamped inside
+        let anchor = Rect::new(80.0, 10.0, 120.0, 90.0);
+        assert_eq!(tooltip_position(anchor, SIZE, UiTooltipPlacement::Below, 5.0, BOUNDS), Vec2::new(80.0, 80.0));
+
+        // Shifted along the side at the corner
+        let anchor = Rect::new(0.0, 0.0, 10.0, 10.0);
+        assert_eq!(tooltip_position(anchor, SIZE, UiTooltipPlacement::Below, 5.0, BOUNDS), Vec2::new(0.0, 15.0));
+        let anchor = Rect::new(190.0, 0.0, 200.0, 10.0);
+        assert_eq!(tooltip_position(anchor, SIZE, UiTooltipPlacement::Below, 5.0, BOUNDS), Vec2::new(160.0, 15.0));
+    }
+
+    #[test]
+    fn inside() {
+        for x in (0..200).step_by(7) {
+            for y in (0..100).step_by(7) {
+                let anchor = Rect::new(x as f32, y as f32, x as f32 + 10.0, y as f32 + 10.0);
+                for placement in [UiTooltipPlacement::Above, UiTooltipPlacement::Below, UiTooltipPlacement::Left, UiTooltipPlacement::Right] {
+                    let pos = tooltip_position(anchor, SIZE, placement, 5.0, BOUNDS);
+                    assert!(BOUNDS.contains(pos) && BOUNDS.contains(pos + SIZE), "{placement:?} at {anchor:?} placed outside at {pos}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use std::{ops::Range, sync::Arc};
+use bevy::ecs::system::EntityCommands;
+
+use crate::*;
+
+
+// #============================#
+// #=== VIRTUAL LIST STRUCTS ===#
+
+/// Callback filling the item entity with components for the item at the index.
+pub type UiItemBuilder = Arc<dyn Fn(&mut EntityCommands, usize) + Send + Sync>;
+
+/// This struct marks an item entity spawned by [`UiVirtualList`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiVirtualItem {
+    /// The entity with the list
+    pub list: Entity,
+    /// Index of the item in the data source
+    pub index: usize,
+}
+
+/// This struct marks entities spawned by [`UiVirtualList`], so they can be despawned with it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub (crate) struct UiVirtualListPart(Entity);
+
+/// **Ui virtual list** - Component that turns a [`UiScroll`] container into a list or grid of fixed size items,
+/// where only the items intersecting the view are spawned. Item entities are recycled as the container scrolls
+/// and the item builder is called each time an item entity is assigned a new index. A spacer node keeps the content
+/// size of all the items, so the [`UiTree`] holds only the visible nodes.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let list = UiVirtualList::new(50_000, 40.0, |item, index| {
+///     item.insert(Name::new(format!("Entry {index}")));
+/// }).columns(2).overscan(2);
+/// ```
+#[derive(Component, Clone)]
+pub struct UiVirtualList {
+    /// Number of items in the data source
+    pub count: usize,
+    /// Size of one item in pixels along the scrolled axis
+    pub item_size: f32,
+    /// Number of items per row, more than one makes a grid
+    pub columns: usize,
+    /// The scrolled axis
+    pub direction: StackDirection,
+    /// Number of extra rows spawned on both sides of the view
+    pub overscan: usize,
+    /// Callback filling the item entities
+    pub builder: UiItemBuilder,
+    /// Spawned item entities and the index they hold
+    pub (crate) slots: Vec<(Entity, Option<usize>)>,
+    /// The spacer entity and the content length it was set to
+    pub (crate) spacer: Option<(Entity, f32)>,
+    /// If all items should be built again
+    pub (crate) rebuild: bool,
+}
+impl UiVirtualList {
+    /// Creates new vertical list with the item count, item size and the item builder.
+    pub fn new(count: usize, item_size: f32, builder: impl Fn(&mut EntityCommands, usize) + Send + Sync + 'static) -> Self {
+        UiVirtualList {
+            count,
+            item_size,
+            columns: 1,
+            direction: StackDirection::Vertical,
+            overscan: 1,
+            builder: Arc::new(builder),
+            slots: Vec::new(),
+            spacer: None,
+            rebuild: false,
+        }
+    }
+    /// Replaces the number of items per row with a new value.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+    /// Replaces the scrolled axis with a new value.
+    pub fn direction(mut self, direction: StackDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+    /// Replaces the number of extra rows with a new value.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+    /// Sets the number of items to a new value. Visible items are built again.
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+        self.rebuild = true;
+    }
+    /// Builds all visible items again, use it when the data source changes.
+    pub fn refresh(&mut self) {
+        self.rebuild = true;
+    }
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.count.div_ceil(self.columns.max(1))
+    }
+    /// Returns the layout of the item at the index.
+    fn item_layout(&self, index: usize) -> UiLayout {
+        let columns = self.columns.max(1);
+        let (row, column) = ((index / columns) as f32, (index % columns) as f32);
+        let width = 100.0 / columns as f32;
+        match self.direction {
+            StackDirection::Vertical => UiLayout::window().pos(Rl(Vec2::new(column * width, 0.0)) + Ab(Vec2::new(0.0, row * self.item_size))).size(Rl(Vec2::new(width, 0.0)) + Ab(Vec2::new(0.0, self.item_size))).pack(),
+            StackDirection::Horizontal => UiLayout::window().pos(Rl(Vec2::new(0.0, column * width)) + Ab(Vec2::new(row * self.item_size, 0.0))).size(Rl(Vec2::new(0.0, width)) + Ab(Vec2::new(self.item_size, 0.0))).pack(),
+        }
+    }
+    /// Returns the layout of the spacer keeping the content size.
+    fn spacer_layout(&self, length: f32) -> UiLayout {
+        match self.direction {
+            StackDirection::Vertical => UiLayout::window().size(Rl(Vec2::new(100.0, 0.0)) + Ab(Vec2::new(0.0, length))).pack(),
+            StackDirection::Horizontal => UiLayout::window().size(Rl(Vec2::new(0.0, 100.0)) + Ab(Vec2::new(length, 0.0))).pack(),
+        }
+    }
+}
+impl std::fmt::Debug for UiVirtualList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UiVirtualList")
+            .field("count", &self.count)
+            .field("item_size", &self.item_size)
+            .field("columns", &self.columns)
+            .field("direction", &self.direction)
+            .field("overscan", &self.overscan)
+            .field("slots", &self.slots)
+            .field("spacer", &self.spacer)
+            .finish_non_exhaustive()
+    }
+}
+
+
+// #==========================#
+// #=== VIRTUAL LIST LOGIC ===#
+
+/// Returns the number of item slots needed to cover the view and the range of item indexes to show.
+/// The offset, view and item size are in the same units. The range never holds more items than there are slots,
+/// so assigning items to slots by the index modulo the slot count never puts two shown items into one slot.
+pub (crate) fn visible_items(count: usize, columns: usize, overscan: usize, offset: f32, view: f32, item_size: f32) -> (usize, Range<usize>) {
+    let columns = columns.max(1);
+    let rows = count.div_ceil(columns);
+    let slot_count = ((view / item_size).ceil() as usize + 1 + 2 * overscan) * columns;
+    let first = ((offset / item_size).floor() as usize).saturating_sub(overscan).min(rows) * columns;
+    let last = (((offset + view) / item_size).ceil() as usize + overscan).min(rows) * columns;
+    (slot_count, first..last.min(count))
+}
+
+/// This system spawns, recycles and despawns the items of [`UiVirtualList`] to cover the scrolled view.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn ui_virtual_list_system<T: Component, N: Default + Component>(
+    mut commands: Commands,
+    uis: Query<&UiTree<T, N>>,
+    mut query: Query<(Entity, &UiLink<T>, &UiScroll, &mut UiVirtualList, &Parent)>,
+) {
+    for (entity, link, scroll, mut list, parent) in &mut query {
+        let list = list.bypass_change_detection();
+        let (offset, view) = match list.direction {
+            StackDirection::Vertical => (scroll.offset.y, scroll.view_size().y),
+            StackDirection::Horizontal => (scroll.offset.x, scroll.view_size().x),
+        };
+        let item_size = list.item_size.max(1.0);
+
+        // The scroll is in computed units, the item size in Ab units
+        let abs_scale = uis.get(parent.get()).ok().and_then(|ui| ui.obtain_topdata()).map(|data| data.abs_scale).unwrap_or(1.0);
+
+        // Keep the content size of all items
+        let length = list.rows() as f32 * item_size;
+        match list.spacer {
+            Some((spacer, spacer_length)) => if spacer_length != length {
+                commands.entity(spacer).insert(list.spacer_layout(length));
+                list.spacer = Some((spacer, length));
+            },
+            None => {
+                let spacer = commands.spawn((
+                    UiNodeBundle::<T> { link: link.add("Spacer"), layout: list.spacer_layout(length) },
+                    UiVirtualListPart(entity),
+                )).id();
+                commands.entity(parent.get()).add_child(spacer);
+                list.spacer = Some((spacer, length));
+            },
+        }
+
+        // Wait until the container is computed
+        if view <= 0.0 { continue; }
+
+        // Spawn or despawn item entities to cover the view with overscan
+        let (slot_count, visible) = visible_items(list.count, list.columns, list.overscan, offset, view, item_size * abs_scale);
+        if list.slots.len() > slot_count {
+            for (item, _) in list.slots.drain(slot_count..) {
+                commands.entity(item).despawn_recursive();
+            }
+        }
+        while list.slots.len() < slot_count {
+            let item = commands.spawn((
+                UiNodeBundle::<T> { link: link.add(format!("Item {}", list.slots.len())), layout: UiLayout::window().size(Ab(0.0)).pack() },
+                SpatialBundle::default(),
+                Dimension::default(),
+                UiVirtualListPart(entity),
+            )).id();
+            commands.entity(parent.get()).add_child(item);
+            list.slots.push((item, None));
+        }
+
+        // Assign the visible items to slots, consecutive indexes never share a slot
+        for index in visible.clone() {
+            let slot = index % slot_count;
+            if list.slots[slot].1 == Some(index) && !list.rebuild { continue; }
+            let mut item = commands.entity(list.slots[slot].0);
+            item.insert((list.item_layout(index), UiVirtualItem { list: entity, index }, Visibility::Inherited));
+            (list.builder)(&mut item, index);
+            list.slots[slot].1 = Some(index);
+        }
+
+        // Hide the slots without a visible item, including the ones left over from a different slot count
+        for (slot, (item, index)) in list.slots.iter_mut().enumerate() {
+            if index.map(|i| visible.contains(&i) && i % slot_count == slot) == Some(true) { continue; }
+            if index.take().is_some() || list.rebuild {
+                commands.entity(*item).insert((UiLayout::window().size(Ab(0.0)).pack::<Base>(), Visibility::Hidden));
+            }
+        }
+        list.rebuild = false;
+    }
+}
+
+/// This system despawns the items and the spacer of removed [`UiVirtualList`] components.
+fn ui_virtual_list_cleanup(mut commands: Commands, mut removed: RemovedComponents<UiVirtualList>, parts: Query<(Entity, &UiVirtualListPart)>) {
+    for list in removed.read() {
+        for (entity, part) in &parts {
+            if part.0 == list { commands.entity(entity).despawn_recursive(); }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct VirtualListPlugin;
+impl Plugin for VirtualListPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, ui_virtual_list_cleanup);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::visible_items;
+
+    #[test]
+    fn range() {
+        // 10 rows fit the view, one row of overscan on both sides
+        assert_eq!(visible_items(1000, 1, 1, 0.0, 400.0, 40.0), (13, 0..11));
+        assert_eq!(visible_items(1000, 1, 1, 420.0, 400.0, 40.0), (13, 9..22));
+        assert_eq!(visible_items(1000, 3, 0, 40.0, 400.0, 40.0), (33, 3..33));
+
+        // Clamped to the item count
+        assert_eq!(visible_items(20, 1, 1, 600.0, 400.0, 40.0).1, 14..20);
+        assert_eq!(visible_items(7, 3, 0, 0.0, 400.0, 40.0).1, 0..7);
+        assert_eq!(visible_items(0, 1, 1, 0.0, 400.0, 40.0).1, 0..0);
+    }
+
+    #[test]
+    fn scale() {
+        // Item size of 40 Ab with abs scale of 2 covers 80 computed units
+        assert_eq!(visible_items(1000, 1, 0, 800.0, 400.0, 80.0), (6, 10..15));
+    }
+
+    #[test]
+    fn no_slot_collision() {
+        for columns in 1..4 {
+            for overscan in 0..3 {
+                for view in [1.0, 37.5, 40.0, 399.0, 400.0, 401.0] {
+                    for step in 0..200 {
+                        let offset = step as f32 * 7.3;
+                        let (slot_count, visible) = visible_items(500, columns, overscan, offset, view, 40.0);
+                        let mut slots = vec![false; slot_count];
+                        for index in visible {
+                            assert!(!std::mem::replace(&mut slots[index % slot_count], true), "Two items in slot {} at offset {offset}", index % slot_count);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
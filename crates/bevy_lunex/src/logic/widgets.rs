@@ -0,0 +1,452 @@
+use bevy::window::PrimaryWindow;
+
+use crate::*;
+
+
+// #======================#
+// #=== SLIDER STRUCTS ===#
+
+/// **Ui slider** - Component holding a value picked by pressing or dragging along the node.
+/// The entity with it is the track, place an entity with [`UiSliderThumb`] inside it to show the value.
+/// It sends [`UiChangeEvent`] with the new value and plays [`UiAnimator<Selected>`] while dragged.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let volume = UiSlider::new(0.0, 100.0).value(80.0).step(5.0);
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiSlider {
+    /// The current value
+    pub value: f32,
+    /// The value at the start of the track
+    pub min: f32,
+    /// The value at the end of the track
+    pub max: f32,
+    /// The value snaps to multiples of the step from the minimum
+    pub step: Option<f32>,
+    /// Horizontal sliders go from left to right, vertical from bottom to top
+    pub direction: StackDirection,
+}
+impl UiSlider {
+    /// Creates new horizontal slider in the range.
+    pub fn new(min: f32, max: f32) -> Self {
+        UiSlider { value: min, min, max, step: None, direction: StackDirection::Horizontal }
+    }
+    /// Replaces the value with a new value.
+    pub fn value(mut self, value: f32) -> Self {
+        self.set_value(value);
+        self
+    }
+    /// Replaces the step with a new value.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+    /// Replaces the direction with a new value.
+    pub fn direction(mut self, direction: StackDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+    /// Sets the value to a new value, snapped to the step and clamped to the range.
+    pub fn set_value(&mut self, value: f32) {
+        let value = match self.step {
+            Some(step) if step > 0.0 => self.min + ((value - self.min) / step).round() * step,
+            _ => value,
+        };
+        self.value = value.clamp(self.min.min(self.max), self.min.max(self.max));
+    }
+    /// Returns how far along the track the value is, ranging from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.max == self.min { return 0.0 }
+        ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+}
+
+/// This struct marks the entity as the thumb of [`UiSlider`]. The position of its [`UiLayout`] is overwritten
+/// to match the value, so it should be a window layout placed inside the slider node. Dragging it drags the slider.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiSliderThumb {
+    /// The entity with the slider
+    pub slider: Entity,
+}
+impl UiSliderThumb {
+    /// Creates new struct
+    pub fn new(slider: Entity) -> Self {
+        UiSliderThumb { slider }
+    }
+}
+
+
+// #======================#
+// #=== TOGGLE STRUCTS ===#
+
+/// **Ui checkbox** - Component holding a value toggled on click.
+/// It sends [`UiChangeEvent`] with `true` or `false` and plays [`UiAnimator<Selected>`] while checked.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let vsync = UiCheckbox::new().checked(true);
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UiCheckbox {
+    /// If the checkbox is checked
+    pub checked: bool,
+}
+impl UiCheckbox {
+    /// Creates new unchecked checkbox.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Replaces the checked state with a new value.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+    /// Sets the checked state to a new value.
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+}
+
+/// **Ui radio group** - Component holding which one of its [`UiRadio`] buttons is selected.
+/// It sends [`UiChangeEvent`] for the group entity with the value of the selected radio.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UiRadioGroup {
+    /// The selected radio entity
+    pub selected: Option<Entity>,
+}
+impl UiRadioGroup {
+    /// Creates new group with nothing selected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// **Ui radio** - Component making the entity a button of [`UiRadioGroup`]. Clicking it selects it and deselects the others.
+/// The selected radio plays [`UiAnimator<Selected>`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// # fn system(mut commands: Commands) {
+/// let group = commands.spawn(UiRadioGroup::new()).id();
+/// commands.spawn(UiRadio::new(group, "Low"));
+/// commands.spawn(UiRadio::new(group, "High"));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct UiRadio {
+    /// The entity with the group
+    pub group: Entity,
+    /// The value sent when selected
+    pub value: String,
+}
+impl UiRadio {
+    /// Creates new radio in the group.
+    pub fn new(group: Entity, value: impl Into<String>) -> Self {
+        UiRadio { group, value: value.into() }
+    }
+}
+
+
+// #=======================#
+// #=== SPINBOX STRUCTS ===#
+
+/// **Ui spinbox** - Component holding a number changed in steps by [`UiSpinboxButton`] clicks.
+/// If the entity has [`Text`], the first section shows the value. With [`UiTextInput`] the value can be also
+/// typed in and is applied on submit. It sends [`UiChangeEvent`] with the new value.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let fov = UiSpinbox::new(60.0, 120.0).value(90.0).step(5.0);
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiSpinbox {
+    /// The current value
+    pub value: f64,
+    /// The lowest value
+    pub min: f64,
+    /// The highest value
+    pub max: f64,
+    /// The amount one step changes the value by
+    pub step: f64,
+    /// Number of decimal places shown
+    pub decimals: usize,
+}
+impl UiSpinbox {
+    /// Creates new spinbox in the range.
+    pub fn new(min: f64, max: f64) -> Self {
+        UiSpinbox { value: min, min, max, step: 1.0, decimals: 0 }
+    }
+    /// Replaces the value with a new value.
+    pub fn value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+    /// Replaces the step with a new value.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+    /// Replaces the number of decimal places with a new value.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+    /// Sets the value to a new value clamped to the range.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = value.clamp(self.min.min(self.max), self.min.max(self.max));
+    }
+    /// Changes the value by the number of steps.
+    pub fn increment(&mut self, steps: f64) {
+        self.set_value(self.value + self.step * steps);
+    }
+    /// Returns the value formatted with the decimal places.
+    pub fn text(&self) -> String {
+        format!("{:.*}", self.decimals, self.value)
+    }
+}
+
+/// This struct makes the entity a button changing [`UiSpinbox`] by the number of steps on click.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiSpinboxButton {
+    /// The entity with the spinbox
+    pub spinbox: Entity,
+    /// Number of steps, negative values decrease the value
+    pub steps: f64,
+}
+impl UiSpinboxButton {
+    /// Creates new button changing the spinbox by the number of steps.
+    pub fn new(spinbox: Entity, steps: f64) -> Self {
+        UiSpinboxButton { spinbox, steps }
+    }
+}
+
+
+// #=====================#
+// #=== WIDGETS LOGIC ===#
+
+/// Plays [`UiAnimator<Selected>`] forward or backward.
+fn select(animator: Option<Mut<UiAnimator<Selected>>>, selected: bool) {
+    let direction = if selected { 1.0 } else { -1.0 };
+    if let Some(mut animator) = animator {
+        if animator.animation_direction != direction { animator.animation_direction = direction; }
+    }
+}
+
+/// This system marks new sliders and their thumbs with [`UiDragCapture`], so dragging them does not scroll.
+fn ui_slider_capture(mut commands: Commands, query: Query<Entity, Or<(Added<UiSlider>, Added<UiSliderThumb>)>>) {
+    for entity in &query {
+        commands.entity(entity).insert(UiDragCapture);
+    }
+}
+
+/// This system sets [`UiSlider`] values from the pointer pressing the track or dragging the slider or its thumb.
+fn ui_slider_drag(
+    mut down: EventReader<Pointer<Down>>,
+    mut drag: EventReader<Pointer<Drag>>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    thumbs: Query<&UiSliderThumb>,
+    mut sliders: Query<(&mut UiSlider, &Dimension, Has<Element>, &GlobalTransform)>,
+    mut change: EventWriter<UiChangeEvent>,
+) {
+    let presses = down.read().filter(|event| sliders.contains(event.target)).map(|event| (event.target, event.pointer_location.clone())).collect::<Vec<_>>();
+    let drags = drag.read().map(|event| (event.target, event.pointer_location.clone())).collect::<Vec<_>>();
+    for (target, location) in presses.into_iter().chain(drags) {
+        let slider_entity = thumbs.get(target).map(|thumb| thumb.slider).unwrap_or(target);
+        let Ok((mut slider, dimension, element, transform)) = sliders.get_mut(slider_entity) else { continue };
+        let Some((_, _, ray)) = picking::pointer_ray(&location, &cameras, &primary_window) else { continue };
+        let Some(local) = picking::ray_hit_plane(ray, transform) else { continue };
+
+        // How far along the track the pointer is
+        let rect = picking::node_rect(dimension, element);
+        let fraction = match slider.direction {
+            StackDirection::Horizontal => (local.x - rect.min.x) / rect.width().max(f32::EPSILON),
+            StackDirection::Vertical => (local.y - rect.min.y) / rect.height().max(f32::EPSILON),
+        }.clamp(0.0, 1.0);
+
+        let mut new = *slider;
+        new.set_value(slider.min + (slider.max - slider.min) * fraction);
+        if new.value != slider.value {
+            slider.value = new.value;
+            change.send(UiChangeEvent { target: slider_entity, value: slider.value.to_string() });
+        }
+    }
+}
+
+/// This system plays [`UiAnimator<Selected>`] of sliders while they or their thumbs are dragged.
+fn ui_slider_animation(
+    mut start: EventReader<Pointer<DragStart>>,
+    mut end: EventReader<Pointer<DragEnd>>,
+    thumbs: Query<&UiSliderThumb>,
+    mut query: Query<Option<&mut UiAnimator<Selected>>, With<UiSlider>>,
+) {
+    let events = start.read().map(|event| (event.target, true)).chain(end.read().map(|event| (event.target, false))).collect::<Vec<_>>();
+    for (target, dragged) in events {
+        let slider = thumbs.get(target).map(|thumb| thumb.slider).unwrap_or(target);
+        if let Ok(animator) = query.get_mut(slider) { select(animator, dragged); }
+    }
+}
+
+/// This system moves [`UiSliderThumb`] layouts to the values of their sliders.
+fn ui_slider_thumb(sliders: Query<&UiSlider>, mut query: Query<(&UiSliderThumb, &mut UiLayout)>) {
+    for (thumb, mut layout) in &mut query {
+        let Ok(slider) = sliders.get(thumb.slider) else { continue };
+        let Layout::Window(mut window) = layout.layout else { continue };
+        let fraction = slider.fraction() * 100.0;
+        window.pos = match slider.direction {
+            StackDirection::Horizontal => Rl((fraction, 50.0)).into(),
+            StackDirection::Vertical => Rl((50.0, 100.0 - fraction)).into(),
+        };
+        window.anchor = lunex_engine::Anchor::Center;
+        if layout.layout != Layout::Window(window) { layout.layout = Layout::Window(window); }
+    }
+}
+
+/// This system toggles checkboxes, selects radios and steps spinboxes on click.
+fn ui_value_widget_click(
+    mut events: EventReader<UiClickEvent>,
+    mut checkboxes: Query<&mut UiCheckbox>,
+    radios: Query<&UiRadio>,
+    mut groups: Query<&mut UiRadioGroup>,
+    buttons: Query<&UiSpinboxButton>,
+    mut spinboxes: Query<&mut UiSpinbox>,
+    mut change: EventWriter<UiChangeEvent>,
+) {
+    for event in events.read() {
+        if let Ok(mut checkbox) = checkboxes.get_mut(event.target) {
+            checkbox.checked = !checkbox.checked;
+            change.send(UiChangeEvent { target: event.target, value: checkbox.checked.to_string() });
+        }
+        if let Ok(radio) = radios.get(event.target) {
+            if let Ok(mut group) = groups.get_mut(radio.group) {
+                if group.selected != Some(event.target) {
+                    group.selected = Some(event.target);
+                    change.send(UiChangeEvent { target: radio.group, value: radio.value.clone() });
+                }
+            }
+        }
+        if let Ok(button) = buttons.get(event.target) {
+            if let Ok(mut spinbox) = spinboxes.get_mut(button.spinbox) {
+                let previous = spinbox.value;
+                spinbox.increment(button.steps);
+                if spinbox.value != previous {
+                    change.send(UiChangeEvent { target: button.spinbox, value: spinbox.text() });
+                }
+            }
+        }
+    }
+}
+
+/// This system applies values typed into spinboxes with [`UiTextInput`].
+fn ui_spinbox_submit(mut events: EventReader<UiSubmitEvent>, mut query: Query<&mut UiSpinbox>, mut change: EventWriter<UiChangeEvent>) {
+    for event in events.read() {
+        let Ok(mut spinbox) = query.get_mut(event.target) else { continue };
+        let Ok(value) = event.value.trim().parse::<f64>() else { continue };
+        let previous = spinbox.value;
+        spinbox.set_value(value);
+        if spinbox.value != previous {
+            change.send(UiChangeEvent { target: event.target, value: spinbox.text() });
+        } else {
+            // Rewrite the clamped value into the input
+            spinbox.set_changed();
+        }
+    }
+}
+
+/// This system shows spinbox values in their [`Text`] or [`UiTextInput`].
+fn ui_spinbox_text(mut query: Query<(&UiSpinbox, Option<&mut Text>, Option<&mut UiTextInput>), Changed<UiSpinbox>>) {
+    for (spinbox, text, input) in &mut query {
+        let value = spinbox.text();
+        if let Some(mut input) = input {
+            if input.value != value { input.set_value(value); }
+        } else if let Some(mut text) = text {
+            if let Some(section) = text.sections.first_mut() {
+                if section.value != value { section.value = value; }
+            }
+        }
+    }
+}
+
+/// This system plays [`UiAnimator<Selected>`] of checked checkboxes and selected radios.
+fn ui_value_widget_animation(
+    groups: Query<&UiRadioGroup, Changed<UiRadioGroup>>,
+    mut checkboxes: Query<(&UiCheckbox, Option<&mut UiAnimator<Selected>>), (Changed<UiCheckbox>, Without<UiRadio>)>,
+    mut radios: Query<(Entity, &UiRadio, Option<&mut UiAnimator<Selected>>)>,
+) {
+    for (checkbox, animator) in &mut checkboxes {
+        select(animator, checkbox.checked);
+    }
+    for (entity, radio, animator) in &mut radios {
+        if let Ok(group) = groups.get(radio.group) {
+            select(animator, group.selected == Some(entity));
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+pub struct WidgetsPlugin;
+impl Plugin for WidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (
+                ui_slider_capture,
+                ui_slider_drag,
+                ui_slider_animation,
+                ui_slider_thumb,
+                ui_value_widget_click,
+                ui_spinbox_submit,
+                ui_spinbox_text,
+                ui_value_widget_animation,
+            ).chain().before(UiSystems::Send));
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{UiSlider, UiSpinbox};
+
+    #[test]
+    fn slider() {
+        let mut slider = UiSlider::new(0.0, 100.0).step(5.0);
+        slider.set_value(82.0);
+        assert_eq!(slider.value, 80.0);
+        assert_eq!(slider.fraction(), 0.8);
+        slider.set_value(120.0);
+        assert_eq!(slider.value, 100.0);
+        slider.set_value(-3.0);
+        assert_eq!(slider.value, 0.0);
+
+        // The step is counted from the minimum
+        let slider = UiSlider::new(1.0, 10.0).step(2.0).value(6.2);
+        assert_eq!(slider.value, 7.0);
+    }
+
+    #[test]
+    fn slider_reversed() {
+        let slider = UiSlider::new(100.0, 0.0).value(25.0);
+        assert_eq!(slider.value, 25.0);
+        assert_eq!(slider.fraction(), 0.75);
+        assert_eq!(UiSlider::new(100.0, 0.0).value(-10.0).value, 0.0);
+        assert_eq!(UiSlider::new(5.0, 5.0).fraction(), 0.0);
+    }
+
+    #[test]
+    fn spinbox() {
+        let mut spinbox = UiSpinbox::new(0.0, 1.0).step(0.25).decimals(2).value(0.5);
+        spinbox.increment(1.0);
+        assert_eq!(spinbox.value, 0.75);
+        assert_eq!(spinbox.text(), "0.75");
+        spinbox.increment(3.0);
+        assert_eq!(spinbox.value, 1.0);
+        spinbox.increment(-10.0);
+        assert_eq!(spinbox.text(), "0.00");
+
+        let spinbox = UiSpinbox::new(60.0, 120.0).value(90.4);
+        assert_eq!(spinbox.text(), "90");
+    }
+}
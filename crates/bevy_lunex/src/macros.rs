@@ -0,0 +1,155 @@
+// #================#
+// #=== UI MACRO ===#
+
+/// Spawns linked nodes nested the same way they are written. The [`UiLink`](crate::UiLink) path of each node is
+/// made from its name and the names of the nodes it is nested in, so the paths are never written twice.
+///
+/// Each node is written as `"Name": [layouts] (components) { subnodes }`, where the components and subnodes are optional.
+/// * Layouts are packed into [`UiLayout`](crate::UiLayout), a layout without a state is packed for `Base`
+///   and `State: layout` is packed for the state
+/// * Components are any components or bundles to insert
+/// * `"Name" as name: ...` binds the spawned entity to a variable usable in the subnodes
+/// * Names can be any expression in parentheses, for example `(format!("Item {i}"))`
+///
+/// Start with `ui!(ui: MainUi { ... })` to spawn nodes from the root of the tree, or with
+/// `ui!(ui, link { ... })` to spawn them under an existing [`UiLink`](crate::UiLink).
+/// The first ident is the [`ChildBuilder`](bevy::prelude::ChildBuilder) of the [`UiTree`](crate::UiTree) entity.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// # fn system(mut commands: Commands, assets: Res<AssetServer>) {
+/// commands.spawn((UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu")), SourceFromCamera)).with_children(|ui| {
+///     ui!(ui: MainUi {
+///         "Root": [UiLayout::boundary().pos1(Ab(20.0)).pos2(Rl(100.0) - Ab(20.0))] {
+///             "Background": [UiLayout::solid().size((Ab(1920.0), Ab(1080.0)))] (
+///                 UiImage2dBundle::from(assets.load("background.png")),
+///             ),
+///             "Volume" as volume: [UiLayout::window().pos(Rl((10.0, 40.0))).size(Rl((80.0, 5.0)))] (
+///                 UiSlider::new(0.0, 100.0),
+///             ) {
+///                 "Thumb": [UiLayout::window().size(Rh(100.0))] (UiSliderThumb::new(volume)),
+///             },
+///             "Button": [
+///                 UiLayout::window().pos(Rl((10.0, 60.0))).size(Rl((80.0, 10.0))),
+///                 Hover: UiLayout::window().pos(Rl((12.0, 60.0))).size(Rl((80.0, 10.0))),
+///             ] (
+///                 UiAnimator::<Hover>::new(),
+///                 UiClickEmitter::SELF,
+///             ),
+///         },
+///     });
+/// });
+/// # }
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lunex_ui {
+    // Nodes from the root of the tree
+    ($ui:ident : $t:ty { $($nodes:tt)* }) => {
+        $crate::__lunex_ui!(@nodes $ui, [root $t] $($nodes)*);
+    };
+
+    // Nodes under an existing link
+    ($ui:ident , $parent:ident { $($nodes:tt)* }) => {
+        $crate::__lunex_ui!(@nodes $ui, [parent $parent] $($nodes)*);
+    };
+
+    // One node at a time, its subnodes are spawned in its scope
+    (@nodes $ui:ident, $parent:tt) => {};
+    (@nodes $ui:ident, $parent:tt $name:tt $(as $var:ident)? : [ $($layouts:tt)* ] $( ( $($components:tt)* ) )? $( { $($nodes:tt)* } )? $(, $($rest:tt)*)?) => {
+        {
+            let link = $crate::__lunex_ui!(@link $parent $name);
+            $crate::__lunex_ui!(@spawn $ui, (link.clone(), $crate::__lunex_ui!(@layouts [] $($layouts)*) $(, ($($components)*))?), $($var)?);
+            $( $crate::__lunex_ui!(@nodes $ui, [parent link] $($nodes)*); )?
+        }
+        $( $crate::__lunex_ui!(@nodes $ui, $parent $($rest)*); )?
+    };
+
+    // Link of the node
+    (@link [root $t:ty] $name:expr) => { $crate::UiLink::<$t>::path($name) };
+    (@link [parent $parent:ident] $name:expr) => { $parent.add($name) };
+
+    // Spawn the node and optionally bind it
+    (@spawn $ui:ident, $bundle:expr, $var:ident) => { let $var = $ui.spawn($bundle).id(); };
+    (@spawn $ui:ident, $bundle:expr,) => { $ui.spawn($bundle); };
+
+    // Pack the layouts into a tuple
+    (@layouts [$($packed:tt)*]) => { ($($packed)*) };
+    (@layouts [$($packed:tt)*] $state:ident : $layout:expr $(, $($rest:tt)*)?) => {
+        $crate::__lunex_ui!(@layouts [$($packed)* $crate::UiLayout::<$state>::from($layout),] $($($rest)*)?)
+    };
+    (@layouts [$($packed:tt)*] $layout:expr $(, $($rest:tt)*)?) => {
+        $crate::__lunex_ui!(@layouts [$($packed)* $crate::UiLayout::<$crate::prelude::Base>::from($layout),] $($($rest)*)?)
+    };
+}
+
+// The macro is exported under an internal name, because exporting it as `ui` would also
+// re-export the `ui` layout module through the same path and shadow it in the prelude.
+#[doc(inline)]
+pub use crate::__lunex_ui as ui;
+
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    /// Builder recording the paths of spawned nodes in place of `ChildBuilder`.
+    #[derive(Default)]
+    struct Builder {
+        paths: Vec<String>,
+    }
+    impl Builder {
+        fn spawn(&mut self, node: impl Node) -> Spawned {
+            self.paths.push(node.path());
+            Spawned(self.paths.len() - 1)
+        }
+    }
+
+    struct Spawned(usize);
+    impl Spawned {
+        fn id(&self) -> usize {
+            self.0
+        }
+    }
+
+    trait Node {
+        fn path(&self) -> String;
+    }
+    impl <L> Node for (UiLink<MainUi>, L) {
+        fn path(&self) -> String { self.0.path.clone() }
+    }
+    impl <L, C> Node for (UiLink<MainUi>, L, C) {
+        fn path(&self) -> String { self.0.path.clone() }
+    }
+
+    #[test]
+    fn root() {
+        let mut ui = Builder::default();
+        ui!(ui: MainUi {
+            "Root": [UiLayout::window_full()] {
+                "Background": [UiLayout::window_full()] (Hover),
+                "List" as list: [UiLayout::window_full(), Hover: UiLayout::window_full()] {
+                    (format!("Item {list}")): [UiLayout::window_full()],
+                    "Footer": [UiLayout::window_full()] () {
+                        "Text": [UiLayout::window_full()],
+                    },
+                },
+            },
+            "Overlay": [UiLayout::window_full()],
+        });
+        assert_eq!(ui.paths, ["Root", "Root/Background", "Root/List", "Root/List/Item 2", "Root/List/Footer", "Root/List/Footer/Text", "Overlay"]);
+    }
+
+    #[test]
+    fn parent() {
+        let mut ui = Builder::default();
+        let menu = UiLink::<MainUi>::path("Menu");
+        ui!(ui, menu {
+            "Button": [UiLayout::window_full()] {
+                "Text": [UiLayout::window_full()],
+            },
+            "Other": [UiLayout::window_full()],
+        });
+        assert_eq!(ui.paths, ["Menu/Button", "Menu/Button/Text", "Menu/Other"]);
+    }
+}
@@ -1,11 +1,11 @@
 use bevy::prelude::*;
 use bevy_mod_picking::{backend::PointerHits, prelude::*};
-use lunex_engine::YInvert;
+use lunex_engine::{NodeDataTrait, NodeGeneralTrait, NodeTopDataTrait, UiTree, YInvert};
 use std::cmp::Ordering;
 use bevy::window::PrimaryWindow;
 use bevy_mod_picking::backend::prelude::*;
 
-use crate::{Dimension, Element};
+use crate::{Dimension, Element, SourceFromCamera, UiClip};
 
 
 // #===============#
@@ -22,10 +22,11 @@ impl Plugin for UiLunexPickingPlugin {
     }
 }
 
-/// Checks if any Dimension entities are under each pointer
+/// Checks if any Dimension entities are under each pointer.
+/// Works with both orthographic and perspective cameras, the pointer ray is intersected with the plane of each node.
 pub fn lunex_picking(
     pointers: Query<(&PointerId, &PointerLocation)>,
-    cameras: Query<(Entity, &Camera, &GlobalTransform, &OrthographicProjection)>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
     node_query: Query<
         (
@@ -34,69 +35,119 @@ pub fn lunex_picking(
             Option<&Element>,
             &GlobalTransform,
             Option<&Pickable>,
+            Option<&UiClip>,
             &ViewVisibility,
         )
     >,
     mut output: EventWriter<PointerHits>,
 ) {
-    let mut sorted_nodes: Vec<_> = node_query.iter().collect();
-    sorted_nodes.sort_by(|a, b| { (b.3.translation().z).partial_cmp(&a.3.translation().z).unwrap_or(Ordering::Equal) });
-
     for (pointer, location) in pointers.iter().filter_map(|(pointer, pointer_location)| { pointer_location.location().map(|loc| (pointer, loc)) }) {
-        let mut blocked = false;
-        let Some((cam_entity, camera, cam_transform, cam_ortho)) = cameras.iter().filter(|(_, camera, _, _)| camera.is_active)
-            .find(|(_, camera, _, _)| {
-                camera
-                    .target
-                    .normalize(Some(match primary_window.get_single() {
-                        Ok(w) => w,
-                        Err(_) => return false,
-                    }))
-                    .unwrap()
-                    == location.target
-            })
-        else { continue; };
-
-        let Some(cursor_pos_world) = camera.viewport_to_world_2d(cam_transform, location.position) else { continue; };
-
-        let picks: Vec<(Entity, HitData)> = sorted_nodes
+        let Some((cam_entity, order, ray)) = pointer_ray(location, &cameras, &primary_window) else { continue; };
+
+        let mut hits: Vec<(Entity, Option<&Pickable>, HitData)> = node_query
             .iter()
-            .copied()
             .filter(|(.., visibility)| visibility.get())
             .filter_map(
-                |(entity, dimension, element, node_transform, pickable, ..)| {
-                    if blocked {
-                        return None;
-                    }
+                |(entity, dimension, element, node_transform, pickable, clip, ..)| {
+                    let position = ray_hit_node(ray, dimension, element.is_some(), node_transform, clip)?;
+
+                    // HitData requires a depth as calculated from the camera's near clipping plane
+                    let depth = ray.origin.distance(position);
+                    let normal = node_transform.back().into();
+                    Some((entity, pickable, HitData::new(cam_entity, depth, Some(position), Some(normal))))
+                },
+            )
+            .collect();
+
+        // Nodes closer to the camera block the ones behind them
+        hits.sort_by(|a, b| a.2.depth.partial_cmp(&b.2.depth).unwrap_or(Ordering::Equal));
+        let mut blocked = false;
+        let picks: Vec<(Entity, HitData)> = hits.into_iter().filter_map(|(entity, pickable, hit)| {
+            if blocked { return None; }
+            blocked = pickable.map(|p| p.should_block_lower) != Some(false);
+            Some((entity, hit))
+        }).collect();
 
-                    let pos = if element.is_none() { dimension.size.invert_y() / 2.0 } else { Vec2::ZERO };
+        output.send(PointerHits::new(*pointer, picks, order));
+    }
+}
 
-                    let rect = Rect::from_center_size(pos, dimension.size);
+/// Returns the active camera rendering to the pointer target, its order and the world space ray under the pointer.
+pub (crate) fn pointer_ray(
+    location: &pointer::Location,
+    cameras: &Query<(Entity, &Camera, &GlobalTransform)>,
+    primary_window: &Query<Entity, With<PrimaryWindow>>,
+) -> Option<(Entity, f32, Ray3d)> {
+    let window = primary_window.get_single().ok()?;
+    let (entity, camera, transform) = cameras.iter()
+        .filter(|(_, camera, _)| camera.is_active)
+        .find(|(_, camera, _)| camera.target.normalize(Some(window)).as_ref() == Some(&location.target))?;
+    let ray = camera.viewport_to_world(transform, location.position)?;
+    Some((entity, camera.order as f32, ray))
+}
 
-                    /* let s = rect.max - rect.min;
-                    let p = (rect.min + s/2.0).extend(0.0) + node_transform.translation();
-                    gizmos.rect(p, Quat::from_rotation_y(0.0), s, Color::linear_rgb(0.0, 0.0, 1.0)); */
+/// Returns the rectangle of the node in its local space.
+pub (crate) fn node_rect(dimension: &Dimension, element: bool) -> Rect {
+    let pos = if element { Vec2::ZERO } else { dimension.size.invert_y() / 2.0 };
+    Rect::from_center_size(pos, dimension.size)
+}
 
-                    // Transform cursor pos to sprite coordinate system
-                    let cursor_pos_sprite = node_transform
-                        .affine()
-                        .inverse()
-                        .transform_point3((cursor_pos_world, 0.0).into());
+/// Returns the center of the node in world space.
+pub (crate) fn node_center(dimension: &Dimension, transform: &GlobalTransform, element: bool) -> Vec3 {
+    transform.transform_point(node_rect(dimension, element).center().extend(0.0))
+}
 
-                    let is_cursor_in_sprite = rect.contains(cursor_pos_sprite.truncate());
-                    blocked = is_cursor_in_sprite && pickable.map(|p| p.should_block_lower) != Some(false);
+/// Returns the rectangle of the node, the Abs scale and the bounds of the tree. Tree coordinates are Y down from the top left corner of the tree.
+pub (crate) fn tree_rect<T: Component, N: Default + Component>(
+    window: &Query<&Window, With<PrimaryWindow>>,
+    (ui, dimension, is_camera_sourced): (&UiTree<T, N>, &Dimension, Option<&SourceFromCamera>),
+    path: &str,
+) -> Option<(Rect, f32, Rect)> {
+    let scale = if let Ok(window) = window.get_single() { window.resolution.scale_factor() } else { 1.0 };
+    let rectangle = ui.borrow_node(path).ok()?.obtain_data()?.rectangle;
+    let abs_scale = ui.obtain_topdata().map(|data| data.abs_scale).unwrap_or(1.0);
+    let bounds = Rect::from_corners(Vec2::ZERO, dimension.size / if is_camera_sourced.is_some() { scale } else { 1.0 });
+    Some((Rect::from_corners(rectangle.pos.truncate(), rectangle.pos.truncate() + rectangle.size), abs_scale, bounds))
+}
 
-                    // HitData requires a depth as calculated from the camera's near clipping plane
-                    let depth = -cam_ortho.near - node_transform.translation().z;
+/// Returns the position of the pointer on the plane of the node in tree coordinates. The node rectangle is in tree coordinates too.
+pub (crate) fn pointer_to_tree(
+    location: &pointer::Location,
+    cameras: &Query<(Entity, &Camera, &GlobalTransform)>,
+    primary_window: &Query<Entity, With<PrimaryWindow>>,
+    rectangle: Rect,
+    dimension: &Dimension,
+    element: bool,
+    transform: &GlobalTransform,
+) -> Option<Vec2> {
+    let (_, _, ray) = pointer_ray(location, cameras, primary_window)?;
+    let local = ray_hit_plane(ray, transform)?.truncate();
+
+    // Local coordinates are Y up from the top left corner, or from the center for elements
+    let node = node_rect(dimension, element);
+    Some(rectangle.min + (local - Vec2::new(node.min.x, node.max.y)).invert_y())
+}
 
-                    is_cursor_in_sprite.then_some((entity, HitData::new(cam_entity, depth, None, None)))
-                },
-            )
-            .collect();
+/// Intersects the ray with the plane of the node and returns the position in the local space of the node.
+pub (crate) fn ray_hit_plane(ray: Ray3d, transform: &GlobalTransform) -> Option<Vec3> {
+    // Transform the ray to node coordinate system and intersect it with the node plane
+    let inverse = transform.affine().inverse();
+    let origin = inverse.transform_point3(ray.origin);
+    let direction = inverse.transform_vector3(*ray.direction);
+    if direction.z.abs() <= f32::EPSILON { return None; }
+    let distance = -origin.z / direction.z;
+    if distance < 0.0 { return None; }
+    Some(origin + direction * distance)
+}
 
-        let order = camera.order as f32;
-        output.send(PointerHits::new(*pointer, picks, order));
+/// Intersects the ray with the plane of the node and returns the world space position of the hit, if it is inside the node and its clip.
+pub (crate) fn ray_hit_node(ray: Ray3d, dimension: &Dimension, element: bool, transform: &GlobalTransform, clip: Option<&UiClip>) -> Option<Vec3> {
+    let cursor_pos_node = ray_hit_plane(ray, transform)?;
+    if !node_rect(dimension, element).contains(cursor_pos_node.truncate()) { return None; }
+    if let Some(clip) = clip.and_then(|clip| clip.rect) {
+        if !clip.contains(cursor_pos_node.truncate()) { return None; }
     }
+    Some(transform.transform_point(cursor_pos_node))
 }
 
 
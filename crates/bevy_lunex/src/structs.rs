@@ -53,6 +53,20 @@ impl UiState for Outro {
     const INDEX: usize = 5;
 }
 
+/// UI state of a component, is the keyframe layout [`UiTimeline`] tweens from
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TimelineFrom;
+impl UiState for TimelineFrom {
+    const INDEX: usize = 6;
+}
+
+/// UI state of a component, is the keyframe layout [`UiTimeline`] tweens to
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TimelineTo;
+impl UiState for TimelineTo {
+    const INDEX: usize = 7;
+}
+
 
 // #=========================#
 // #=== MARKER COMPONENTS ===#
@@ -85,7 +99,8 @@ impl Dimension {
     }
 }
 
-/// # WIP - used for Div layout
+/// This struct holds the size of the content. Used by [`ui::Div`] layout to size itself
+/// if it has no other Div nodes inside. Text elements pipe their size into it automatically.
 #[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
 pub struct UiContent {
     pub size: Vec2,
@@ -181,6 +196,16 @@ impl UiLayout {
     pub fn div() -> ui::Div {
         ui::Div::new()
     }
+    /// **Grid** - Parametric layout type that is defined by the cell and span. Its location and size is based on
+    /// the [`UiGrid`] of the parent [`UiStack`]. Nodes without a cell are placed into the next free cell.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use lunex_engine::UiLayout;
+    /// let layout: UiLayout = UiLayout::grid().cell((1, 0)).span((2, 1)).pack();
+    /// ```
+    pub fn grid() -> ui::Grid {
+        ui::Grid::new()
+    }
 }
 impl <S> UiLayout<S> {
     /// Creates struct from layout
@@ -245,23 +270,59 @@ impl PackageLayout for ui::Div {
         UiLayout::<S>::from(self)
     }
 }
+impl <S> From<ui::Grid> for UiLayout<S> {
+    fn from(val: ui::Grid) -> Self {
+        val.pack::<S>()
+    }
+}
+impl PackageLayout for ui::Grid {
+    fn pack<S>(self) -> UiLayout<S> {
+        UiLayout::<S>::from(self)
+    }
+}
 
-/// This struct controls what 2 layouts should be computed and lerped between.
+/// This struct controls what layouts should be computed and blended together.
+/// The base layout is tweened to the second one and every weighted layout in `blend` is added on top.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let mut controller = UiLayoutController::default();
+/// controller.set_weight(Hover::INDEX, 0.5);
+/// controller.set_weight(Clicked::INDEX, 1.0);
+/// ```
 #[derive(Component, Debug, Clone, PartialEq)]
 pub struct UiLayoutController {
     /// Indexes of the two layouts to tween between
     pub index: [usize; 2],
     /// The transition ranging from 0.0 to 1.0
     pub tween: f32,
-    /// The method called for smoothing the tween value
-    pub method: fn(f32) -> f32,
+    /// Indexes of additional layouts and their weights ranging from 0.0 to 1.0, they are used as they are
+    /// because [`UiAnimator`] already eases them
+    pub blend: Vec<(usize, f32)>,
+    /// The easing curve used for smoothing the tween value
+    pub method: UiEase,
+}
+impl UiLayoutController {
+    /// Returns the blend weight of the layout.
+    pub fn weight(&self, index: usize) -> f32 {
+        self.blend.iter().find(|(i, _)| *i == index).map(|(_, weight)| *weight).unwrap_or(0.0)
+    }
+    /// Sets the blend weight of the layout. Layouts with zero weight are removed.
+    pub fn set_weight(&mut self, index: usize, weight: f32) {
+        match self.blend.iter_mut().find(|(i, _)| *i == index) {
+            Some((_, w)) => *w = weight,
+            None => self.blend.push((index, weight)),
+        }
+        self.blend.retain(|(_, weight)| *weight != 0.0);
+    }
 }
 impl Default for UiLayoutController {
     fn default() -> Self {
         UiLayoutController { 
             index: [0, 0],
             tween: 0.0,
-            method: |i|{i},
+            blend: Vec::new(),
+            method: UiEase::Linear,
         }
     }
 }
@@ -304,6 +365,12 @@ impl <T> Default for UiLink<T> {
 }
 
 
+/// This struct marks linked UI entities to keep their [`UiTree`] node when they are despawned or their [`UiLink`] is removed.
+/// Without it the node is removed together with its subnodes.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct UiNodeKeep;
+
+
 /// This struct holds depth bias that will be relatively added to `depth` in the layout calculation.
 /// Nodes with higher depth bias will be placed on top of nodes with lower depth bias.
 /// It is recursive.
@@ -311,6 +378,93 @@ impl <T> Default for UiLink<T> {
 pub struct UiDepthBias (pub f32);
 
 
+/// This struct makes the node a scroll container. Its subnodes are offset by the scroll and clipped to its rectangle.
+/// The offset is driven by mouse wheel, pointer dragging and gamepad right stick over the container.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let scroll = UiScroll::new().axis(BVec2::new(false, true)).friction(4.0);
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct UiScroll {
+    /// Offset the content is scrolled by
+    pub offset: Vec2,
+    /// Speed the content keeps scrolling at after dragging, in pixels per second
+    pub velocity: Vec2,
+    /// How fast the velocity slows down, higher values stop sooner
+    pub friction: f32,
+    /// How many pixels is scrolled per mouse wheel line
+    pub speed: f32,
+    /// Axes that can be scrolled
+    pub axis: BVec2,
+    /// Size of the content fetched from the node
+    pub (crate) content: Vec2,
+    /// Size of the container fetched from the node
+    pub (crate) view: Vec2,
+}
+impl UiScroll {
+    /// Creates new struct
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Replaces the friction with a new value.
+    pub fn friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+    /// Replaces the speed with a new value.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+    /// Replaces the axes with a new value.
+    pub fn axis(mut self, axis: BVec2) -> Self {
+        self.axis = axis;
+        self
+    }
+    /// Scrolls to the offset, it is clamped to the content.
+    pub fn scroll_to(&mut self, offset: impl Into<Vec2>) {
+        self.offset = offset.into().clamp(Vec2::ZERO, self.max_offset());
+        self.velocity = Vec2::ZERO;
+    }
+    /// Returns the size of the content.
+    pub fn content_size(&self) -> Vec2 {
+        self.content
+    }
+    /// Returns the size of the container.
+    pub fn view_size(&self) -> Vec2 {
+        self.view
+    }
+    /// Returns the largest offset the content can be scrolled by.
+    pub fn max_offset(&self) -> Vec2 {
+        (self.content - self.view).max(Vec2::ZERO)
+    }
+}
+impl Default for UiScroll {
+    fn default() -> Self {
+        UiScroll {
+            offset: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+            friction: 5.0,
+            speed: 40.0,
+            axis: BVec2::TRUE,
+            content: Vec2::ZERO,
+            view: Vec2::ZERO,
+        }
+    }
+}
+
+
+/// This struct holds the rectangle the entity is clipped to, in the local space of the entity.
+/// Lunex fetches it from [`UiTree`] for nodes inside scroll containers or nodes with [`UiOverflow`] other than visible.
+/// It is respected by picking, sprites and meshes. Text is hidden only when it is completely outside of it,
+/// use [`UiOverflow::Hidden`] to hide text that sticks out.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+pub struct UiClip {
+    pub rect: Option<Rect>,
+}
+
+
 // #====================#
 // #=== MAIN BUNDLES ===#
 
@@ -1,5 +1,5 @@
 use crate::*;
-use bevy::{math::Vec3A, render::primitives::Aabb, sprite::Mesh2dHandle, text::TextLayoutInfo, window::PrimaryWindow};
+use bevy::{math::Vec3A, render::{mesh::VertexAttributeValues, primitives::Aabb}, sprite::Mesh2dHandle, text::TextLayoutInfo, window::PrimaryWindow};
 use lunex_engine::*;
 
 
@@ -7,11 +7,12 @@ use lunex_engine::*;
 // #=== CORE SYSTEM ===#
 
 /// This system computes [`UiTree`] with data from querried [`Dimension`] component if there is a change.
+/// Only nodes marked as dirty are recomputed, unless the [`Dimension`] changed.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 /// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
 pub fn compute_ui<T:Component, N:Default + Component>(
-    mut query: Query<(&Dimension, &mut UiTree<T, N>, Option<&SourceFromCamera>), (With<UiLink<T>>, Or<(Changed<UiTree<T, N>>, Changed<Dimension>)>)>,
+    mut query: Query<(Ref<Dimension>, &mut UiTree<T, N>, Option<&SourceFromCamera>), (With<UiLink<T>>, Or<(Changed<UiTree<T, N>>, Changed<Dimension>)>)>,
     window: Query<&bevy::window::Window, With<PrimaryWindow>>,
 ) {
     let scale = if let Ok(window) = window.get_single() { window.resolution.scale_factor() } else { 1.0 };
@@ -19,6 +20,9 @@ pub fn compute_ui<T:Component, N:Default + Component>(
         #[cfg(feature = "verbose")]
         info!("{} {} - {}", "<>".red(), "UiTree".purple().bold(), "Recomputed".underline().bold());
         let scale = if is_camera_sourced.is_none() { 1.0 } else { scale };
+
+        // Resized tree has to be recomputed whole, otherwise only dirty nodes are recomputed
+        if dimension.is_changed() { let _ = ui.mark_dirty("."); }
         ui.compute(Rectangle2D::new().with_size(dimension.size / scale).into());
     }
 }
@@ -151,6 +155,54 @@ pub fn touch_camera_if_uitree_added<T:Component, N:Default + Component>(
 // #========================#
 // #=== PIPING FOR NODES ===#
 
+/// This system keeps [`UiTree`] nodes in sync with linked entities. If [`UiLink`] path changes, it moves the node
+/// with its subnodes to the new path. If the linked entity is despawned or the [`UiLink`] is removed, it removes the node,
+/// unless the entity is marked with [`UiNodeKeep`].
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn sync_link_to_node<T:Component, N:Default + Component>(
+    mut links: Local<bevy::utils::HashMap<Entity, (Entity, String, bool)>>,
+    mut uis: Query<&mut UiTree<T, N>>,
+    query: Query<(Entity, &UiLink<T>, &Parent, Has<UiNodeKeep>), (Or<(Changed<UiLink<T>>, Added<UiNodeKeep>)>, Without<UiTree<T, N>>)>,
+    mut removed: RemovedComponents<UiLink<T>>,
+) {
+    // Remove nodes of unlinked entities
+    for entity in removed.read() {
+        let Some((tree, path, keep)) = links.remove(&entity) else { continue };
+        if keep { continue; }
+        if let Ok(mut ui) = uis.get_mut(tree) {
+            if ui.remove_node(path.clone()).is_ok() {
+                #[cfg(feature = "verbose")]
+                info!("{} {} - Removed orphaned node", "--".yellow(), path.yellow().bold());
+                let _ = ui.mark_dirty(path.rsplit_once('/').map(|(parent, _)| parent).unwrap_or("."));
+            }
+        }
+    }
+
+    // Move nodes of re-targeted entities
+    for (entity, link, parent, keep) in &query {
+        if let Some((tree, path, _)) = links.get(&entity) {
+            if *path != link.path {
+                if let Ok(mut ui) = uis.get_mut(*tree) {
+                    if let Ok(node) = ui.remove_node(path.clone()) {
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Moved node to {}", "--".yellow(), path.yellow().bold(), link.path.yellow().bold());
+                        let _ = ui.remove_node(link.path.clone());
+                        let _ = match link.path.rsplit_once('/') {
+                            None => ui.add_node(link.path.clone(), node),
+                            Some((rempath, name)) => ui.borrow_or_create_ui_node_mut(rempath).and_then(|subnode| subnode.add_node(name, node)),
+                        };
+                        let _ = ui.mark_dirty(path.rsplit_once('/').map(|(parent, _)| parent).unwrap_or("."));
+                        let _ = ui.mark_dirty(link.path.clone());
+                    }
+                }
+            }
+        }
+        links.insert(entity, (parent.get(), link.path.clone(), keep));
+    }
+}
+
 /// This system takes [`UiLayout`] data and overwrites coresponding [`UiTree`] data. If node is not found, it creates new ones along the path.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -197,15 +249,17 @@ pub fn send_layout_control_to_node<T:Component, N:Default + Component>(
                         #[cfg(feature = "verbose")]
                         info!("{} {} - Tweening between [{}] [{}] - {}", "->".blue(), link.path.yellow().bold(), control.index[0], control.index[1], control.tween);
                         container.layout_index = control.index;
-                        container.layout_tween = (control.method)(control.tween);
+                        container.layout_tween = control.method.ease(control.tween);
+                        container.layout_blend = control.blend.clone();
                     }
                 }
+                // Recompute only the changed part of the tree
+                let _ = ui.mark_dirty(link.path.clone());
             }
         }
     }
 }
 
-/// # WORK IN PROGRESS!!! DOES NOTHING CURRENTLY.
 /// This system takes [`UiStack`] data and overwrites coresponding [`UiTree`] data.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -227,6 +281,36 @@ pub fn send_stack_to_node<T:Component, N:Default + Component>(
                         container.stack = stack.clone();
                     }
                 }
+                // Recompute only the changed part of the tree
+                let _ = ui.mark_dirty(link.path.clone());
+            }
+        }
+    }
+}
+
+/// This system takes [`UiOverflow`] data and overwrites coresponding [`UiTree`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_overflow_to_node<T:Component, N:Default + Component>(
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<(&UiLink<T>, &UiOverflow), Changed<UiOverflow>>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, overflow)) = query.get(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data_mut() {
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Received Overflow data", "->".blue(), link.path.yellow().bold());
+                        container.overflow = *overflow;
+                    }
+                }
+                // Recompute only the changed part of the tree
+                let _ = ui.mark_dirty(link.path.clone());
             }
         }
     }
@@ -253,12 +337,42 @@ pub fn send_depth_bias_to_node<T:Component, N:Default + Component>(
                         container.depth_bias = bias.0;
                     }
                 }
+                // Recompute only the changed part of the tree
+                let _ = ui.mark_dirty(link.path.clone());
+            }
+        }
+    }
+}
+
+/// This system takes [`UiScroll`] data and overwrites coresponding [`UiTree`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_scroll_to_node<T:Component, N:Default + Component>(
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<(&UiLink<T>, &UiScroll), Changed<UiScroll>>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, scroll)) = query.get(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data_mut() {
+                        if container.scroll == Some(scroll.offset) { continue; }
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Received Scroll data", "->".blue(), link.path.yellow().bold());
+                        container.scroll = Some(scroll.offset);
+                    }
+                }
+                // Recompute only the changed part of the tree
+                let _ = ui.mark_dirty(link.path.clone());
             }
         }
     }
 }
 
-/// # WORK IN PROGRESS!!! DOES NOTHING CURRENTLY.
 /// This system takes [`UiContent`] data and overwrites coresponding [`UiTree`] data.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -280,6 +394,8 @@ pub fn send_content_size_to_node<T:Component, N:Default + Component>(
                         container.content_size = content.size;
                     }
                 }
+                // Recompute only the changed part of the tree
+                let _ = ui.mark_dirty(link.path.clone());
             }
         }
     }
@@ -339,6 +455,74 @@ pub fn fetch_dimension_from_node<T:Component, N:Default + Component>(
     }
 }
 
+/// This system fetches computed [`UiTree`] data and overwrites querried [`UiScroll`] content and container sizes.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_scroll_from_node<T:Component, N:Default + Component>(
+    uis: Query<(&UiTree<T, N>, &Children), Changed<UiTree<T, N>>>,
+    mut query: Query<(&UiLink<T>, &mut UiScroll)>,
+) {
+    for (ui, children) in &uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, mut scroll)) = query.get_mut(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_node(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data() {
+                        if scroll.content != container.content_extent || scroll.view != container.rectangle.size {
+                            #[cfg(feature = "verbose")]
+                            info!("{} {} - Linked {} fetched Scroll data from node", "<-".bright_green(), link.path.yellow().bold(), "ENTITY".blue());
+                            scroll.content = container.content_extent;
+                            scroll.view = container.rectangle.size;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// This system fetches computed [`UiTree`] data and overwrites querried [`UiClip`] data. The clip rectangle is converted
+/// into the local space of the entity, so it can be compared with [`Dimension`] directly.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_clip_from_node<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    uis: Query<(&UiTree<T, N>, &Children), Changed<UiTree<T, N>>>,
+    mut query: Query<(Entity, &UiLink<T>, Option<&mut UiClip>, Has<Element>)>,
+) {
+    for (ui, children) in &uis {
+        for child in children {
+            // If child matches
+            if let Ok((entity, link, clip_option, is_element)) = query.get_mut(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_node(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data() {
+                        let rect = container.clip.map(|clip| {
+                            // Offset from the top-left corner of the node, flipped to Y up
+                            let offset = clip.pos - container.rectangle.pos.truncate();
+                            let rect = Rect::new(offset.x, -offset.y - clip.size.y, offset.x + clip.size.x, -offset.y);
+                            if is_element { Rect::from_center_size(rect.center() - container.rectangle.size.invert_y() / 2.0, rect.size()) } else { rect }
+                        });
+                        match clip_option {
+                            Some(mut clip) => if clip.rect != rect {
+                                #[cfg(feature = "verbose")]
+                                info!("{} {} - Linked {} fetched Clip data from node", "<-".bright_green(), link.path.yellow().bold(), "ENTITY".blue());
+                                clip.rect = rect;
+                            },
+                            None => if rect.is_some() { commands.entity(entity).insert(UiClip { rect }); },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// This system takes computed [`UiTree`] data and overwrites querried [`Transform`] + [`Element`] data in specific way.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -380,6 +564,55 @@ pub fn element_sprite_size_from_dimension<T: Component>(
     }
 }
 
+/// This system takes updated [`UiClip`] data and crops querried [`Sprite`] data to fit inside it.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn element_clip_sprite<T: Component>(
+    images: Res<Assets<Image>>,
+    mut query: Query<(&mut Sprite, &Dimension, &UiClip, &Handle<Image>), (With<UiLink<T>>, With<Element>, Or<(Changed<UiClip>, Changed<Dimension>)>)>,
+) {
+    for (mut sprite, dimension, clip, handle) in &mut query {
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Clipped sprite", "--".yellow(), "ELEMENT".red());
+        let full = Rect::from_center_size(Vec2::ZERO, dimension.size);
+        let Some(visible) = clip.rect.map(|rect| rect.intersect(full)) else {
+            sprite.custom_size = Some(dimension.size);
+            sprite.rect = None;
+            sprite.anchor = bevy::sprite::Anchor::Center;
+            continue;
+        };
+        if visible.is_empty() || dimension.size.cmple(Vec2::ZERO).any() {
+            sprite.custom_size = Some(Vec2::ZERO);
+            continue;
+        }
+
+        // Shrink the sprite and move it over the visible part
+        sprite.custom_size = Some(visible.size());
+        sprite.anchor = bevy::sprite::Anchor::Custom(-visible.center() / visible.size());
+
+        // Crop the texture the same way, texture coordinates go from the top-left corner
+        if let Some(image) = images.get(handle) {
+            let scale = image.size_f32() / dimension.size;
+            let min = Vec2::new(visible.min.x - full.min.x, full.max.y - visible.max.y) * scale;
+            let max = Vec2::new(visible.max.x - full.min.x, full.max.y - visible.min.y) * scale;
+            sprite.rect = Some(Rect::from_corners(min, max));
+        }
+    }
+}
+
+/// This system takes updated [`UiClip`] data and hides querried [`Text`] that is completely outside of it.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn element_clip_text<T: Component>(
+    mut query: Query<(&mut Visibility, &Dimension, &UiClip), (With<UiLink<T>>, With<Element>, With<Text>, Or<(Changed<UiClip>, Changed<Dimension>)>)>,
+) {
+    for (mut visibility, dimension, clip) in &mut query {
+        let outside = clip.rect.map(|rect| rect.intersect(Rect::from_center_size(Vec2::ZERO, dimension.size)).is_empty()).unwrap_or(false);
+        let new = if outside { Visibility::Hidden } else { Visibility::Inherited };
+        if *visibility != new { *visibility = new; }
+    }
+}
+
 /// This system takes updated [`Dimension`] data and overwrites querried [`Handle<Image>`] data to fit.
 /// This is used to resize manually created render targets for secondary cameras, not textures.
 /// ## 📦 Types
@@ -402,18 +635,22 @@ pub fn element_image_size_from_dimension<T: Component>(
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 pub fn element_reconstruct_mesh<T: Component>(
     mut msh: ResMut<Assets<Mesh>>,
-    mut query: Query<(&Dimension, Option<&mut Handle<Mesh>>, Option<&mut Mesh2dHandle>, Option<&mut Aabb>), (With<UiLink<T>>, With<Element>, Or<(Changed<Dimension>, Added<Mesh2dHandle>)>)>,
+    mut query: Query<(&Dimension, Option<&UiClip>, Option<&mut Handle<Mesh>>, Option<&mut Mesh2dHandle>, Option<&mut Aabb>), (With<UiLink<T>>, With<Element>, Or<(Changed<Dimension>, Changed<UiClip>, Added<Mesh2dHandle>)>)>,
 ) {
-    for (dimension, mut mesh_option, mut mesh2d_option, mut aabb_option) in &mut query {
+    for (dimension, clip_option, mut mesh_option, mut mesh2d_option, mut aabb_option) in &mut query {
 
         #[cfg(feature = "verbose")]
         info!("{} {} - Reconstructed mesh size", "--".yellow(), "ELEMENT".red());
 
+        // Only the part inside the clip rectangle is constructed
+        let full = Rect::from_center_size(Vec2::ZERO, dimension.size);
+        let visible = clip_option.and_then(|clip| clip.rect).map(|rect| rect.intersect(full)).unwrap_or(full);
+
         if let Some(aabb) = aabb_option.as_mut() {
             // Create new culling boundary
             **aabb = Aabb {
-                center: Vec3A::ZERO,
-                half_extents: Vec3A::new(dimension.size.x/2.0, dimension.size.y/2.0, 1.0),
+                center: visible.center().extend(0.0).into(),
+                half_extents: Vec3A::new(visible.half_size().x, visible.half_size().y, 1.0),
             };
         }
 
@@ -422,7 +659,7 @@ pub fn element_reconstruct_mesh<T: Component>(
             let _ = msh.remove(mesh.id());
 
             // Create new mesh
-            **mesh = msh.add(Rectangle {half_size: dimension.size / 2.0});
+            **mesh = msh.add(clipped_rectangle(full, visible));
         }
 
         if let Some(mesh2d) = mesh2d_option.as_mut() {
@@ -430,11 +667,31 @@ pub fn element_reconstruct_mesh<T: Component>(
             let _ = msh.remove(mesh2d.0.id());
 
             // Create new mesh
-            **mesh2d = Mesh2dHandle(msh.add(Rectangle {half_size: dimension.size / 2.0}));
+            **mesh2d = Mesh2dHandle(msh.add(clipped_rectangle(full, visible)));
         }
     }
 }
 
+/// Creates rectangle mesh covering the visible part of the full rectangle, with texture coordinates of the full rectangle.
+fn clipped_rectangle(full: Rect, visible: Rect) -> Mesh {
+    let mut mesh = Mesh::from(Rectangle { half_size: visible.half_size() });
+    if visible == full { return mesh }
+    if let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION) {
+        for position in positions.iter_mut() {
+            position[0] += visible.center().x;
+            position[1] += visible.center().y;
+        }
+    }
+    let uvs: Vec<[f32; 2]> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => positions.iter().map(|p| {
+            [(p[0] - full.min.x) / full.width().max(f32::EPSILON), (full.max.y - p[1]) / full.height().max(f32::EPSILON)]
+        }).collect(),
+        _ => return mesh,
+    };
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh
+}
+
 /// This system takes updated [`TextLayoutInfo`] data and overwrites coresponding [`Layout`] data to match the text size.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -467,7 +724,6 @@ pub fn element_text_size_to_layout<T: Component>(
     }
 }
 
-/// # WORK IN PROGRESS!!! DOES NOTHING CURRENTLY.
 /// This system takes updated [`TextLayoutInfo`] data and overwrites coresponding [`UiContent`] data to match the text size.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -563,11 +819,14 @@ impl <T:Component, N:Default + Component> Plugin for UiCorePlugin<T, N> {
             ).in_set(UiSystems::Modify).before(UiSystems::Send))
 
             .add_systems(Update, (
+                sync_link_to_node::<T, N>,
                 send_layout_to_node::<T, N, Base>,
                 send_content_size_to_node::<T, N>,
                 send_stack_to_node::<T, N>,
                 send_layout_control_to_node::<T, N>,
-                send_depth_bias_to_node::<T, N>
+                send_depth_bias_to_node::<T, N>,
+                send_overflow_to_node::<T, N>,
+                send_scroll_to_node::<T, N>,
             ).chain().in_set(UiSystems::Send).before(UiSystems::Compute))
 
             .add_systems(Update, (
@@ -578,10 +837,13 @@ impl <T:Component, N:Default + Component> Plugin for UiCorePlugin<T, N> {
                 fetch_transform_from_node::<T, N>,
                 fetch_dimension_from_node::<T, N>,
                 element_fetch_transform_from_node::<T, N>,
+                fetch_scroll_from_node::<T, N>,
+                fetch_clip_from_node::<T, N>,
             ).in_set(UiSystems::Fetch).after(UiSystems::Compute))
 
             .add_systems(Update, (
-                element_sprite_size_from_dimension::<T>,
+                (element_sprite_size_from_dimension::<T>, element_clip_sprite::<T>).chain(),
+                element_clip_text::<T>,
                 element_image_size_from_dimension::<T>,
                 element_text_size_scale_fit_to_dimension::<T>,
                 element_reconstruct_mesh::<T>,
@@ -1,17 +1,36 @@
 use bevy::ecs::component::Component;
 
 use crate::import::*;
+use crate::NodeError;
 use crate::NodeGeneralTrait;
 use crate::NodeTopDataTrait;
 use crate::UiNode;
 use crate::UiTree;
+use crate::NodeData;
+use crate::Rectangle2D;
 use crate::Rectangle3D;
+use crate::UiOverflow;
 use crate::Layout;
+use crate::Div;
+use crate::Grid;
+use crate::UiGrid;
+use crate::compute_tracks;
+use crate::UiStack;
+use crate::StackDirection;
+use crate::StackMargin;
+use crate::UiValue;
+use crate::UiValueEvaluate;
 
 /// Trait with [`UiTree`] layout computation methods.
 pub trait UiNodeTreeComputeTrait {
-    /// Compute the layout of the [`UiTree`].
+    /// Compute the layout of the [`UiTree`]. Only dirty nodes and their subnodes are recomputed.
+    /// ## 📌 Note
+    /// * If the parent rectangle changed, mark the root node `"."` as dirty first
     fn compute(&mut self, parent: Rectangle3D);
+    /// ## 🚸 Recursive
+    /// Marks the node as dirty so it is recomputed with all of its subnodes on the next compute.
+    /// Use `"."` to mark the root node and recompute the whole tree.
+    fn mark_dirty(&mut self, path: impl Borrow<str>) -> Result<(), NodeError>;
 }
 impl <T, N: Default + Component> UiNodeTreeComputeTrait for UiTree<T, N> {
     fn compute(&mut self, parent: Rectangle3D) {
@@ -24,311 +43,761 @@ impl <T, N: Default + Component> UiNodeTreeComputeTrait for UiTree<T, N> {
             font_size = master_data.font_size;
         }
 
-        self.node.compute_all(parent, abs_scale, parent.size, font_size);
+        self.node.compute_all(parent, None, false, abs_scale, parent.size, font_size);
+    }
+    fn mark_dirty(&mut self, path: impl Borrow<str>) -> Result<(), NodeError> {
+        mark_dirty(&mut self.node, path.borrow())
     }
 }
 
 
+/// Marks the node on the path as dirty and all nodes along the path as having dirty subnodes.
+pub(crate) fn mark_dirty<N:Default + Component>(node: &mut UiNode<N>, path: &str) -> Result<(), NodeError> {
+    match path.split_once('/') {
+        None => {
+            if let Some(node_data) = &mut node.obtain_node_mut(path)?.data { node_data.dirty = true }
+        },
+        Some((name, rempath)) => mark_dirty(node.obtain_node_mut(name)?, rempath)?,
+    }
+    if path != "." {
+        if let Some(node_data) = &mut node.data { node_data.dirty_subnodes = true }
+    }
+    Ok(())
+}
+
+
 /// Trait with [`UiNode`] layout computation methods. Includes private methods.
 trait UiNodeComputeTrait {
-    fn compute_all(&mut self, parent: Rectangle3D, absolute_scale: f32, viewport_size: Vec2, font_size: f32);
-    //fn compute_content(&mut self, ancestor_size: Vec2, ancestor_padding: Vec4, abs_scale: f32, font_size: f32) -> Vec2;
-    //fn compute_stack(&mut self, ancestor_size: Vec2, ancestor_padding: Vec4, abs_scale: f32, font_size: f32, horizontal: bool) -> Vec2;
-    //fn align_stack(&mut self, ancestor_position: Vec2);
+    #[allow(clippy::too_many_arguments)]
+    fn compute_all(&mut self, parent: Rectangle3D, clip: Option<Rectangle2D>, force: bool, absolute_scale: f32, viewport_size: Vec2, font_size: f32);
+    fn requires_reflow(&self) -> bool;
+    fn hide_all(&mut self);
+    fn compute_content(&mut self, origin: Vec2, size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Vec2;
+    #[allow(clippy::too_many_arguments)]
+    fn compute_grid(&mut self, grid: &UiGrid, stack: &UiStack, origin: Vec2, size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32);
+    fn measure_grid(&self, grid: &UiGrid, stack: &UiStack, size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Option<ComputedGrid>;
+    fn measure_content(&self, size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Vec2;
+    fn measure_div(&self, available: Vec2, inherited_margin: &UiValue<Vec4>, in_grid: bool, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Option<ComputedDiv>;
 }
-impl <N:Default + Component> UiNodeComputeTrait for UiNode<N> { 
+impl <N:Default + Component> UiNodeComputeTrait for UiNode<N> {
     /// Triggers the recursion in the right manner.
-    fn compute_all(&mut self, parent: Rectangle3D, absolute_scale: f32, viewport_size: Vec2, mut font_size: f32) {
+    fn compute_all(&mut self, parent: Rectangle3D, clip: Option<Rectangle2D>, force: bool, absolute_scale: f32, viewport_size: Vec2, mut font_size: f32) {
 
         // Get depth before mutating self
         let depth = self.get_depth();
-        
-        let skip = true;
-        let is_parametric = false;
 
-        // Check here if computation is required for partial recalculation
+        // Changed parametric subnodes require the flow of this node to be recomputed
+        let reflow = self.nodes.values().any(|subnode| subnode.requires_reflow());
 
         // Compute my layout and return computed rectangle for recursion
-        let my_rectangle = if let Some(node_data) = &mut self.data {
+        let (my_rectangle, scroll, overflow, is_parametric, dirty) = if let Some(node_data) = &mut self.data {
+
+            // Skip the subtree if nothing changed
+            let dirty = force || reflow || node_data.dirty;
+            if !dirty && !node_data.dirty_subnodes { return; }
+            node_data.dirty = false;
+            node_data.dirty_subnodes = false;
 
             // Overwrite passed style with font size
             if let Some(fnt) = node_data.font_size { font_size = fnt }
 
             // Compute node layout
+            let layout_0 = primary_layout(node_data);
+            let is_parametric = matches!(layout_0, Layout::Div(_) | Layout::Grid(_));
 
-            let layout_0 = node_data.layout.get(&node_data.layout_index[0]).unwrap_or(node_data.layout.get(&0).unwrap());
-            let layout_0: Option<Rectangle3D> = match layout_0 {
-                Layout::Div(_) => {
-                    None
-                },
-                Layout::Boundary(l) => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-                Layout::Window(l) => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-                Layout::Solid(l)  => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-            };
-
-            let layout_1 = node_data.layout.get(&node_data.layout_index[1]).unwrap_or(node_data.layout.get(&0).unwrap());
-            let layout_1: Option<Rectangle3D> = match layout_1 {
-                Layout::Div(_) => {
-                    None
-                },
-                Layout::Boundary(l) => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-                Layout::Window(l) => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-                Layout::Solid(l)  => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-            };
-
-            /* match &node_data.layout {
-                Layout::Div(_) => {
-                    is_parametric = true;
-                },
-                Layout::Boundary(l) => {
-                    skip = false;
-                },
-                Layout::Window(l) => {
-                    skip = false;
-                },
-                Layout::Solid(l)  => {
-                    skip = false;
-                },
-            } */
-
-            if let Some(l0) = layout_0 {
-                if let Some(l1) = layout_1 {
-                    node_data.rectangle = l0.lerp(l1, node_data.layout_tween);
-                };
-            };
+            // Parametric layout is computed by the parent flow, so we keep it as is
+            if dirty && !is_parametric {
+                node_data.rectangle = compute_blend(node_data, parent, absolute_scale, viewport_size, font_size);
+            }
 
             // Adding depth
             node_data.rectangle.pos.z = (depth + node_data.depth_bias)*absolute_scale;
-            node_data.rectangle
+            node_data.clip = clip;
+            (node_data.rectangle, node_data.scroll, node_data.overflow, is_parametric, dirty)
 
         } else { return; };
 
-        if !skip {
-            if is_parametric {
-                //compute divs with inherited scale
-                //self.compute_content(parent.size, Vec4::ZERO, absolute_scale, font_size);
-            } else {
-                //compute divs with my rectangle scale
-                //self.compute_content(my_rectangle.size, Vec4::ZERO, absolute_scale, font_size);
-            }
+        // Scroll containers offset their content and clip it to their rectangle
+        let mut content_rectangle = my_rectangle;
+        let overflow = if scroll.is_some() && overflow == UiOverflow::Visible { UiOverflow::Clip } else { overflow };
+        if let Some(scroll) = scroll {
+            content_rectangle.pos -= scroll.extend(0.0);
+        }
+
+        // Clipping nodes narrow down the inherited clip
+        let rectangle: Rectangle2D = my_rectangle.into();
+        let content_clip = match overflow {
+            UiOverflow::Visible => clip,
+            UiOverflow::Clip | UiOverflow::Hidden => Some(clip.map(|clip| clip.intersect(rectangle)).unwrap_or(rectangle)),
+        };
+
+        // Compute divs with my rectangle, parametric nodes had their content computed by the parent flow
+        if dirty && !is_parametric {
+            self.compute_content(content_rectangle.pos.xy(), content_rectangle.size, absolute_scale, viewport_size, font_size);
         }
 
-        // Enter recursion
+        // Enter recursion, everything below a recomputed node is recomputed too
         for (_, subnode) in &mut self.nodes {
-            subnode.compute_all(my_rectangle, absolute_scale, viewport_size, font_size);
+            subnode.compute_all(content_rectangle, content_clip, dirty, absolute_scale, viewport_size, font_size);
         }
-    }
-    /* /// Computes the content only.
-    fn compute_content(&mut self, ancestor_size: Vec2, ancestor_padding: Vec4, abs_scale: f32, font_size: f32) -> Vec2 {
 
-        let stack_options = self.data.as_ref().unwrap().stack;
+        // Hide subnodes sticking out, they were just given the clip by the recursion
+        if overflow == UiOverflow::Hidden {
+            for (_, subnode) in &mut self.nodes {
+                let outside = subnode.data.as_ref().zip(content_clip).map(|(data, clip)| !clip.contains(data.rectangle.into())).unwrap_or(false);
+                if outside { subnode.hide_all(); }
+            }
+        }
 
-        match stack_options.direction {
-            StackDirection::Horizontal => self.compute_stack(ancestor_size, ancestor_padding, abs_scale, font_size, true),
-            StackDirection::Vertical => self.compute_stack(ancestor_size, ancestor_padding, abs_scale, font_size, false),
+        // Measure the scrollable area
+        if scroll.is_some() {
+            let extent = self.nodes.values().filter_map(|subnode| subnode.data.as_ref()).fold(Vec2::ZERO, |extent, subnode_data| {
+                extent.max(subnode_data.rectangle.pos.xy() + subnode_data.rectangle.size - content_rectangle.pos.xy())
+            });
+            if let Some(node_data) = &mut self.data { node_data.content_extent = extent }
         }
     }
-    /// This will compute the stack and position nodes ONLY locally as if every matrix starts at 0,0.
-    /// Secondary pass after alignment of parent nodes is required.
-    fn compute_stack(&mut self, ancestor_size: Vec2, ancestor_padding: Vec4, abs_scale: f32, font_size: f32, horizontal: bool) -> Vec2 {
 
-        let mut matrix: Vec<Vec<&mut Node<NodeData<N>>>> = Vec::new();
-        let mut content_size = Vec2::ZERO;
+    /// Checks if this node is parametric and changed, or contains parametric subnodes that changed.
+    fn requires_reflow(&self) -> bool {
+        let Some(node_data) = &self.data else { return false };
+        if !matches!(primary_layout(node_data), Layout::Div(_) | Layout::Grid(_)) { return false }
+        node_data.dirty || (node_data.dirty_subnodes && self.nodes.values().any(|subnode| subnode.requires_reflow()))
+    }
 
-        // Sort mutable pointers into matrix
-        let mut i = 0;
-        matrix.push(Vec::new());
+    /// Clips this node and all of its subnodes to nothing.
+    fn hide_all(&mut self) {
+        if let Some(node_data) = &mut self.data {
+            node_data.clip = Some(Rectangle2D::new().with_pos(node_data.rectangle.pos.xy()));
+        }
         for (_, subnode) in &mut self.nodes {
-            if let Some(subnode_data) = &subnode.data {
-                if let Layout::Div(layout) = &subnode_data.layout {
-                    let br = layout.force_break;
-                    matrix[i].push(subnode);
-                    if br {
-                        i += 1;
-                        matrix.push(Vec::new());
-                    }
-                }
-            }
+            subnode.hide_all();
         }
+    }
 
+    /// Positions all Div subnodes inside the given content box and returns the size of the content.
+    fn compute_content(&mut self, origin: Vec2, size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Vec2 {
 
-        // INSIDE MATRIX =================================================================
-
-        let gap = self.data.as_ref().unwrap().stack.gap.evaluate(abs_scale, ancestor_size, font_size);
-        let align = self.data.as_ref().unwrap().stack.node_alignment.0;
-
+        // Fetch the stack of this node
+        let Some(node_data) = &self.data else { return Vec2::ZERO };
+        let stack = node_data.stack.clone();
 
-        let mut line_cursor = if horizontal { ancestor_padding.y } else { ancestor_padding.x };
+        // Place grid subnodes into their cells
+        if let Some(grid) = &stack.grid {
+            self.compute_grid(grid, &stack, origin, size, absolute_scale, viewport_size, font_size);
+        }
 
-        //--------------------------//
-        let mut _i = 0;             //
-        let _i_max = matrix.len();  //
-        for line in &mut matrix {   //
-            // INSIDE LINE =================================================================
+        let gap = stack.gap.evaluate(Vec2::splat(absolute_scale), size, viewport_size, Vec2::splat(font_size));
+        let inherited_margin = inherited_margin(&stack);
 
-            // Register that is shared between the two passes
-            let mut comline = ComputedLine {
-                divs: Vec::new(),
-                line_length: 0.0,
-            };
+        // First pass to compute sizes
+        let mut divs = Vec::new();
+        for (name, subnode) in &self.nodes {
+            if let Some(div) = subnode.measure_div(size, &inherited_margin, stack.grid.is_some(), absolute_scale, viewport_size, font_size) {
+                divs.push((name.clone(), div));
+            }
+        }
+        if divs.is_empty() { return Vec2::ZERO }
+
+        // Second pass to align them
+        let computed: Vec<ComputedDiv> = divs.iter().map(|(_, div)| *div).collect();
+        let (offsets, content) = compute_flow(&stack, gap, &computed, size);
+
+        for ((name, div), offset) in divs.into_iter().zip(offsets) {
+            let subnode = self.nodes.get_mut(&name).unwrap();
+            let Some(subnode_data) = &mut subnode.data else { continue };
+
+            // Set the computed rectangle
+            let subnode_font = subnode_data.font_size.unwrap_or(font_size);
+            subnode_data.rectangle.pos.x = origin.x + offset.x;
+            subnode_data.rectangle.pos.y = origin.y + offset.y;
+            subnode_data.rectangle.size = div.size;
+
+            // Enter recursion with the content box of the subnode
+            let subnode_origin = subnode_data.rectangle.pos.xy() + div.padding.xy() + div.border.xy();
+            let subnode_size = (div.size - div.padding.xy() - div.padding.zw() - div.border.xy() - div.border.zw()).max(Vec2::ZERO);
+            subnode.compute_content(subnode_origin, subnode_size, absolute_scale, viewport_size, subnode_font);
+        }
 
-            // First pass to compute sizes--//
-            let mut _ii = 0;                //
-            let _ii_max = line.len();       //
-            for subnode in &mut *line {     //
-                // INSIDE SUBNODE =================================================================
+        content
+    }
 
-                // Fetch data
-                let subnode_data = subnode.data.as_ref().unwrap();
-                let layout = if let Layout::Div(layout) = subnode_data.layout { layout } else { unreachable!() };
+    /// Positions all Grid subnodes inside the given content box.
+    fn compute_grid(&mut self, grid: &UiGrid, stack: &UiStack, origin: Vec2, size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) {
 
-                // Get padding & margin => compute range of motion
-                let padding = layout.compute_padding(ancestor_size, abs_scale, font_size);
-                let margin = layout.compute_margin(ancestor_size, abs_scale, font_size);
-                let border = layout.compute_border(ancestor_size, abs_scale, font_size);
+        // Compute the tracks and the cells of the subnodes
+        let Some(ComputedGrid { items, columns, rows }) = self.measure_grid(grid, stack, size, absolute_scale, viewport_size, font_size) else { return };
 
-                // Enter recursion to get the right content size
-                let potential_content = subnode.compute_content(ancestor_size, padding, abs_scale, font_size);
+        for (name, cell, span) in items {
+            let subnode = self.nodes.get_mut(&name).unwrap();
+            let Some(subnode_data) = &mut subnode.data else { continue };
 
-                // Fetch data again, because they were modified
-                let subnode_data = subnode.data.as_mut().unwrap();
-                let mut subnode_content = subnode_data.content_size;
+            // Span the cells
+            let (start, end) = (cell, cell + span - UVec2::ONE);
+            let mut pos = Vec2::new(columns[start.x as usize].0, rows[start.y as usize].0);
+            let cell_size = Vec2::new(columns[end.x as usize].0 + columns[end.x as usize].1, rows[end.y as usize].0 + rows[end.y as usize].1) - pos;
 
-                // Overwrite subnode content if div contains no subdivs
-                if potential_content != Vec2::ZERO { subnode_content = potential_content }
+            // Mirror the cells if required
+            if stack.inverted { pos.x = size.x - pos.x - cell_size.x }
+            if stack.flipped { pos.y = size.y - pos.y - cell_size.y }
 
-                // Compute size and (line_length)
-                let size = layout.compute_size(subnode_content, padding, border);
-                let line_length = if horizontal { margin.y + size.y + margin.w } else { margin.x + size.x + margin.z };
+            // Set the computed rectangle
+            let subnode_font = subnode_data.font_size.unwrap_or(font_size);
+            subnode_data.rectangle.pos.x = origin.x + pos.x;
+            subnode_data.rectangle.pos.y = origin.y + pos.y;
+            subnode_data.rectangle.size = cell_size;
 
-                // Push into register
-                comline.line_length = f32::max(comline.line_length, line_length);
-                comline.divs.push(ComputedDiv { size, margin });
+            // Enter recursion with the cell of the subnode
+            subnode.compute_content(origin + pos, cell_size, absolute_scale, viewport_size, subnode_font);
+        }
+    }
 
+    /// Assigns cells to all Grid subnodes and computes the tracks, returns `None` if there are no Grid subnodes.
+    fn measure_grid(&self, grid: &UiGrid, stack: &UiStack, size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Option<ComputedGrid> {
+
+        // Collect the grid subnodes in insertion order
+        let items: Vec<(String, Grid)> = self.nodes.iter().filter_map(|(name, subnode)| match primary_layout(subnode.data.as_ref()?) {
+            Layout::Grid(layout) => Some((name.clone(), *layout)),
+            _ => None,
+        }).collect();
+        if items.is_empty() { return None }
+
+        // Assign cells to the subnodes
+        let horizontal = stack.direction == StackDirection::Horizontal;
+        let main_count = if horizontal { grid.columns.len() } else { grid.rows.len() };
+        let layouts: Vec<Grid> = items.iter().map(|(_, layout)| *layout).collect();
+        let cells = place_grid(horizontal, main_count as u32, &layouts);
+
+        // Compute the tracks, implicit ones are added if needed
+        let count = cells.iter().fold(UVec2::ZERO, |count, (cell, span)| count.max(*cell + *span));
+        let gap = grid.gap.evaluate(Vec2::splat(absolute_scale), size, viewport_size, Vec2::splat(font_size));
+        let columns = compute_tracks(&grid.columns, count.x as usize, gap.x, size.x, absolute_scale, viewport_size.x, font_size);
+        let rows = compute_tracks(&grid.rows, count.y as usize, gap.y, size.y, absolute_scale, viewport_size.y, font_size);
+
+        let items = items.into_iter().zip(cells).map(|((name, _), (cell, span))| (name, cell, span)).collect();
+        Some(ComputedGrid { items, columns, rows })
+    }
 
-                // END OF INSIDE SUBNODE =================================================================
-                _ii += 1;
-            }
+    /// Computes the size of the content without positioning anything.
+    fn measure_content(&self, size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Vec2 {
+        let Some(node_data) = &self.data else { return Vec2::ZERO };
 
-            if _i != 0 { line_cursor += if horizontal { gap.x } else { gap.y } }
-            let mut cursor = if horizontal { ancestor_padding.x } else { ancestor_padding.y };
+        // Grid subnodes take the size of all tracks and gaps
+        let grid = node_data.stack.grid.as_ref().and_then(|grid| self.measure_grid(grid, &node_data.stack, size, absolute_scale, viewport_size, font_size)).map(|grid| grid.size());
 
-            // Second pass to align them----//
-            let mut _ii = 0;                //
-            let _ii_max = line.len();       //
-            for subnode in &mut *line {     //
-                // INSIDE SUBNODE =================================================================
+        let inherited_margin = inherited_margin(&node_data.stack);
+        let divs: Vec<ComputedDiv> = self.nodes.values().filter_map(|subnode| subnode.measure_div(size, &inherited_margin, node_data.stack.grid.is_some(), absolute_scale, viewport_size, font_size)).collect();
 
-                // Fetch data
-                let subnode_data = subnode.data.as_ref().unwrap();
-                let layout = if let Layout::Div(layout) = subnode_data.layout { layout } else { unreachable!() };
-                
-                let margin = comline.divs[_ii].margin;
-                let size = comline.divs[_ii].size;
+        // Use content size if div contains no subdivs
+        if divs.is_empty() { return grid.unwrap_or(node_data.content_size * absolute_scale) }
 
-                let possible_size = if horizontal {comline.line_length - margin.y - margin.w } else {comline.line_length - margin.x - margin.z };
+        let gap = node_data.stack.gap.evaluate(Vec2::splat(absolute_scale), size, viewport_size, Vec2::splat(font_size));
+        compute_flow(&node_data.stack, gap, &divs, size).1.max(grid.unwrap_or(Vec2::ZERO))
+    }
 
-                let mut my_align = align;
-                let my_offset;
-                
-                if horizontal {
-                    if let Some(align) = layout.align_y { my_align = align.0 }
+    /// Computes the size of this node if it is a Div, or a Grid outside of a grid, returns `None` otherwise.
+    fn measure_div(&self, available: Vec2, inherited_margin: &UiValue<Vec4>, in_grid: bool, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Option<ComputedDiv> {
+        let node_data = self.data.as_ref()?;
+        let layout = match primary_layout(node_data) {
+            Layout::Div(layout) => *layout,
+            // Grid nodes without a grid to be placed into flow like a default Div
+            Layout::Grid(_) if !in_grid => Div::new(),
+            _ => return None,
+        };
+        let font_size = node_data.font_size.unwrap_or(font_size);
+
+        // Get padding & margin => compute range of motion
+        let padding = layout.compute_padding(available, absolute_scale, viewport_size, font_size);
+        let border = layout.compute_border(available, absolute_scale, viewport_size, font_size);
+        let margin = layout.compute_margin(available, absolute_scale, viewport_size, font_size) + inherited_margin.evaluate(Vec4::splat(absolute_scale), available.extend(available.x).extend(available.y), viewport_size.extend(viewport_size.x).extend(viewport_size.y), Vec4::splat(font_size));
+        let space = layout.compute_margin_sp() + inherited_margin.evaluate_sp();
+
+        let outer = (available - margin.xy() - margin.zw()).max(Vec2::ZERO);
+        let inner = (outer - padding.xy() - padding.zw() - border.xy() - border.zw()).max(Vec2::ZERO);
+
+        // Enter recursion to get the right content size
+        let content = self.measure_content(inner, absolute_scale, viewport_size, font_size);
+        let size = layout.compute_size(content, padding, border, outer, available, absolute_scale, viewport_size, font_size);
+
+        Some(ComputedDiv { size, padding, border, margin, space, br: layout.br })
+    }
+}
 
-                    if _ii != 0 { cursor += gap.x }
-                    cursor += margin.x;
-                    let off = margin.y + possible_size/2.0 - size.y/2.0;
-                    my_offset = Vec2::new(cursor, off + (off - margin.x) * my_align);
-                    cursor += size.x;
-                    cursor += margin.z;
 
-                } else {
-                    if let Some(align) = layout.align_x { my_align = align.0 }
+/// Computed tracks of a grid and the cells of its Grid subnodes.
+#[derive(Debug, Clone)]
+struct ComputedGrid {
+    /// Names of the subnodes with their cell and span
+    items: Vec<(String, UVec2, UVec2)>,
+    /// Offsets and sizes of the columns
+    columns: Vec<(f32, f32)>,
+    /// Offsets and sizes of the rows
+    rows: Vec<(f32, f32)>,
+}
+impl ComputedGrid {
+    /// Returns the size of all tracks together with the gaps between them.
+    fn size(&self) -> Vec2 {
+        let end = |tracks: &[(f32, f32)]| tracks.last().map(|(offset, length)| offset + length).unwrap_or(0.0);
+        Vec2::new(end(&self.columns), end(&self.rows))
+    }
+}
 
-                    if _ii != 0 { cursor += gap.y }
-                    cursor += margin.y;
-                    let off = margin.x + possible_size/2.0 - size.x/2.0;
-                    my_offset = Vec2::new(off + (off - margin.x) * my_align, cursor);
-                    cursor += size.y;
-                    cursor += margin.w;
-                };
-                
+/// Computed values of a Div node required for the flow.
+#[derive(Debug, Clone, Copy)]
+struct ComputedDiv {
+    size: Vec2,
+    padding: Vec4,
+    border: Vec4,
+    margin: Vec4,
+    space: Vec4,
+    br: bool,
+}
 
-                // Fetch data again, because they were modified
-                let subnode_data = subnode.data.as_mut().unwrap();
+/// Returns the layout the node is primarily computed from.
+fn primary_layout<N:Default + Component>(node_data: &NodeData<N>) -> &Layout {
+    node_data.layout.get(&node_data.layout_index[0]).unwrap_or(node_data.layout.get(&0).unwrap())
+}
 
-                let _xxx = my_offset + if horizontal { Vec2::new(0.0, line_cursor) } else { Vec2::new(line_cursor, 0.0) };
-                subnode_data.rectangle.pos.x = _xxx.x;
-                subnode_data.rectangle.pos.y = _xxx.y;
-                subnode_data.rectangle.size = size;
+/// Computes the base layout and adds the weighted difference of every blended layout on top of it.
+/// The tween between the two layout indexes is blended the same way, so it stays a plain lerp.
+fn compute_blend<N:Default + Component>(node_data: &NodeData<N>, parent: Rectangle3D, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Rectangle3D {
+    let base = compute_layout(primary_layout(node_data), parent, absolute_scale, viewport_size, font_size).unwrap_or_default();
+
+    let mut rectangle = base;
+    let weights = std::iter::once((node_data.layout_index[1], node_data.layout_tween)).chain(node_data.layout_blend.iter().copied());
+    for (index, weight) in weights {
+        if weight == 0.0 { continue }
+        let Some(layout) = node_data.layout.get(&index) else { continue };
+        let Some(target) = compute_layout(layout, parent, absolute_scale, viewport_size, font_size) else { continue };
+        rectangle = Rectangle3D {
+            pos: rectangle.pos + (target.pos - base.pos) * weight,
+            size: rectangle.size + (target.size - base.size) * weight,
+            roll: rectangle.roll + (target.roll - base.roll) * weight,
+            yaw: rectangle.yaw + (target.yaw - base.yaw) * weight,
+            tilt: rectangle.tilt + (target.tilt - base.tilt) * weight,
+        };
+    }
+    rectangle
+}
 
+/// Computes the declarative layouts, returns `None` for parametric ones.
+fn compute_layout(layout: &Layout, parent: Rectangle3D, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Option<Rectangle3D> {
+    match layout {
+        Layout::Div(_) | Layout::Grid(_) => None,
+        Layout::Boundary(l) => Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into()),
+        Layout::Window(l) => Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into()),
+        Layout::Solid(l) => Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into()),
+    }
+}
 
-                let subnode_data = subnode.data.as_ref().unwrap();
-                subnode.align_stack(subnode_data.rectangle.pos.xy());
+/// Returns the margin all Div subnodes inherit from [`StackMargin::Manual`].
+fn inherited_margin(stack: &UiStack) -> UiValue<Vec4> {
+    match &stack.margin {
+        StackMargin::Manual(margin) => **margin,
+        _ => UiValue::new(),
+    }
+}
 
+/// Returns the [`crate::Sp`] weights on the start and the end of the main axis that a div inherits from the [`StackMargin`] preset.
+fn preset_space(margin: &StackMargin, index: usize, count: usize) -> Vec2 {
+    let (first, last) = (index == 0, index + 1 == count);
+    let weight = |value: bool| if value { 1.0 } else { 0.0 };
+    match margin {
+        StackMargin::Start | StackMargin::Manual(_) => Vec2::ZERO,
+        StackMargin::Center => Vec2::new(weight(first), weight(last)),
+        StackMargin::End => Vec2::new(weight(first), 0.0),
+        StackMargin::Between => Vec2::new(weight(!first), 0.0),
+        StackMargin::Evenly => Vec2::new(1.0, weight(last)),
+        StackMargin::Around => Vec2::ONE,
+    }
+}
 
-                // END OF INSIDE SUBNODE =================================================================
-                _ii += 1;
-            }
+/// Assigns a cell and span to each grid item. Explicit cells are placed first, the rest is auto-placed
+/// into the next free cells in insertion order, filling the main axis first. Returns `(cell, span)` pairs.
+fn place_grid(horizontal: bool, main_count: u32, items: &[Grid]) -> Vec<(UVec2, UVec2)> {
+
+    // Swizzle the values so we can work with main & cross axis only
+    let axis = |v: UVec2| if horizontal { v } else { v.yx() };
+    let main_count = main_count.max(1);
+
+    let mut occupied: HashSet<UVec2> = HashSet::new();
+    let fits = |occupied: &HashSet<UVec2>, cell: UVec2, span: UVec2| (0..span.x).all(|x| (0..span.y).all(|y| !occupied.contains(&(cell + UVec2::new(x, y)))));
+    let occupy = |occupied: &mut HashSet<UVec2>, cell: UVec2, span: UVec2| for x in 0..span.x { for y in 0..span.y { occupied.insert(cell + UVec2::new(x, y)); } };
+
+    // Explicit cells take priority
+    let mut cells = vec![None; items.len()];
+    for (i, item) in items.iter().enumerate() {
+        if let Some(cell) = item.cell {
+            let (cell, span) = (axis(cell), axis(item.span.max(UVec2::ONE)));
+            occupy(&mut occupied, cell, span);
+            cells[i] = Some((cell, span));
+        }
+    }
 
-            // Set content size
-            if horizontal {
-                content_size.x = f32::max(content_size.x, cursor - ancestor_padding.x)
+    // Auto-place the rest
+    let mut cursor = UVec2::ZERO;
+    for (i, item) in items.iter().enumerate() {
+        if cells[i].is_some() { continue }
+        let mut span = axis(item.span.max(UVec2::ONE));
+        span.x = span.x.min(main_count);
+
+        loop {
+            if cursor.x + span.x > main_count {
+                cursor = UVec2::new(0, cursor.y + 1);
+            } else if fits(&occupied, cursor, span) {
+                break;
             } else {
-                content_size.y = f32::max(content_size.y, cursor - ancestor_padding.y)
+                cursor.x += 1;
             }
+        }
 
-            line_cursor += comline.line_length;
+        occupy(&mut occupied, cursor, span);
+        cells[i] = Some((cursor, span));
+        cursor.x += span.x;
+    }
 
-            // END OF INSIDE LINE =================================================================
-            _i += 1;
+    cells.into_iter().flatten().map(|(cell, span)| (axis(cell), axis(span))).collect()
+}
+
+/// Splits the divs into lines and positions them locally as if the content box starts at `0,0`.
+/// Free space on each line is distributed by [`crate::Sp`] weights, then the result is mirrored if the stack is flipped or inverted.
+/// Returns the offsets of each div and the size of the whole content.
+fn compute_flow(stack: &UiStack, gap: Vec2, divs: &[ComputedDiv], bounds: Vec2) -> (Vec<Vec2>, Vec2) {
+
+    // Swizzle the values so we can work with main & cross axis only
+    let horizontal = stack.direction == StackDirection::Horizontal;
+    let axis = |v: Vec2| if horizontal { v } else { v.yx() };
+    let (gap, size) = (axis(gap), axis(bounds));
+    let margin_start = |m: Vec4| axis(m.xy());
+    let margin_end = |m: Vec4| axis(m.zw());
+
+    // Sort the divs into lines
+    let mut lines: Vec<Vec<usize>> = vec![Vec::new()];
+    let mut line_length = 0.0;
+    for (i, div) in divs.iter().enumerate() {
+        let (start, end, div_size) = (margin_start(div.margin), margin_end(div.margin), axis(div.size));
+
+        // Wrap if the div doesn't fit on the line
+        if !lines.last().unwrap().is_empty() && line_length + gap.x + (start.x + div_size.x + end.x) > size.x {
+            lines.push(Vec::new());
+            line_length = 0.0;
         }
 
-        // Set content size
-        if horizontal {
-            content_size.y = line_cursor - ancestor_padding.y
-        } else {
-            content_size.x = line_cursor - ancestor_padding.x
+        // Accumulate in the same order as the cursor below
+        let line = lines.last_mut().unwrap();
+        if !line.is_empty() { line_length += gap.x }
+        line.push(i);
+        line_length += start.x + div_size.x + end.x;
+
+        // Force a line break
+        if div.br {
+            lines.push(Vec::new());
+            line_length = 0.0;
         }
-        
-        // END OF INSIDE MATRIX =========================================================
-        content_size
     }
-    /// This is the secondary pass to align the nodes.
-    fn align_stack(&mut self, ancestor_position: Vec2) {
+    lines.retain(|line| !line.is_empty());
+
+    // Position the divs
+    let mut margins: Vec<Vec4> = divs.iter().map(|div| div.margin).collect();
+    let mut offsets = vec![Vec2::ZERO; divs.len()];
+    let mut content = Vec2::ZERO;
+    let mut line_cursor = 0.0;
+    for (l, line) in lines.iter().enumerate() {
+        if l != 0 { line_cursor += gap.y }
+
+        // Compute the natural size of the line
+        let mut line_length = gap.x * (line.len() - 1) as f32;
+        let mut line_size: f32 = 0.0;
+        for i in line {
+            let div = &divs[*i];
+            let outer = margin_start(div.margin) + axis(div.size) + margin_end(div.margin);
+            line_length += outer.x;
+            line_size = line_size.max(outer.y);
+        }
 
-        for (_, subnode) in &mut self.nodes {
-            if let Some(subnode_data) = &mut subnode.data {
-                if let Layout::Div(_) = &subnode_data.layout {
+        // Collect the Sp weights of the divs and the stack preset
+        let weights: Vec<(Vec2, Vec2)> = line.iter().enumerate().map(|(ii, i)| {
+            let preset = preset_space(&stack.margin, ii, line.len());
+            (margin_start(divs[*i].space) + Vec2::new(preset.x, 0.0), margin_end(divs[*i].space) + Vec2::new(preset.y, 0.0))
+        }).collect();
+
+        // Free space on the main axis is shared across the line, a single line spans the whole cross axis
+        let total = weights.iter().map(|(start, end)| start.x + end.x).sum::<f32>();
+        let main_unit = if total > 0.0 { (size.x - line_length).max(0.0) / total } else { 0.0 };
+        let cross_size = if lines.len() == 1 { line_size.max(size.y) } else { line_size };
+
+        let mut cursor = 0.0;
+        for (ii, (i, (start_weight, end_weight))) in line.iter().zip(weights).enumerate() {
+            let div = &divs[*i];
+            let (start, end, div_size) = (margin_start(div.margin), margin_end(div.margin), axis(div.size));
+
+            // Free space on the cross axis is shared by the div only
+            let cross_weight = start_weight.y + end_weight.y;
+            let cross_unit = if cross_weight > 0.0 { (cross_size - start.y - div_size.y - end.y).max(0.0) / cross_weight } else { 0.0 };
+            let unit = Vec2::new(main_unit, cross_unit);
+            let (start, end) = (start + start_weight * unit, end + end_weight * unit);
+
+            if ii != 0 { cursor += gap.x }
+            offsets[*i] = axis(Vec2::new(cursor + start.x, line_cursor + start.y));
+            cursor += start.x + div_size.x + end.x;
+
+            let (start, end) = (axis(start), axis(end));
+            margins[*i] = Vec4::new(start.x, start.y, end.x, end.y);
+        }
 
-                    subnode_data.rectangle.pos.x += ancestor_position.x;
-                    subnode_data.rectangle.pos.y += ancestor_position.y;
+        content.x = content.x.max(line_length);
+        line_cursor += line_size;
+    }
+    content.y = line_cursor;
 
-                }
-                subnode.align_stack(ancestor_position);
-            }
+    // Mirror the outer boxes, margins stay on their physical side
+    if stack.inverted || stack.flipped {
+        for ((offset, div), margin) in offsets.iter_mut().zip(divs).zip(margins) {
+            if stack.inverted { offset.x = bounds.x - offset.x - div.size.x - margin.z + margin.x }
+            if stack.flipped { offset.y = bounds.y - offset.y - div.size.y - margin.w + margin.y }
         }
+    }
 
-    } */
+    (offsets, axis(content))
 }
-/* 
-struct ComputedDiv {
-    size: Vec2,
-    margin: Vec4,
-}
-struct ComputedLine {
-    divs: Vec<ComputedDiv>,
-    line_length: f32,
+
+
+// #=============#
+// #=== TESTS ===#
+
+#[cfg(test)]
+mod test {
+    use crate::{NodeDataTrait, NodeGeneralTrait, UiNodeCreationTrait, UiNodeTreeInitTrait, UiTree, UiOverflow, Rectangle2D, Layout, UiStack, UiGrid, GridTrack, StackDirection, StackMargin, Ab, Rl, Sp, Sizing};
+    use super::UiNodeTreeComputeTrait;
+    use bevy::math::Vec2;
+
+    fn tree(nodes: &[(&str, Layout)]) -> UiTree {
+        let mut tree: UiTree = UiTree::new2d("Test");
+        for (path, layout) in nodes {
+            let node = tree.borrow_or_create_ui_node_mut(*path).unwrap();
+            node.obtain_data_mut().unwrap().layout.insert(0, *layout);
+        }
+        tree
+    }
+    fn rectangle(tree: &UiTree, path: &str) -> Rectangle2D {
+        tree.borrow_node(path).unwrap().obtain_data().unwrap().rectangle.into()
+    }
+
+    #[test]
+    fn div_flow() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().pos(Ab(10.0)).size(Ab(500.0)).into()),
+            ("Root/A", Layout::div().pad(Ab(10.0)).margin_l(Ab(5.0)).min(Ab(50.0)).into()),
+            ("Root/B", Layout::div().pad(Ab(10.0)).border(Ab(5.0)).br().into()),
+            ("Root/C", Layout::div().min(Ab((100.0, 20.0))).width(Sizing::Max).max_width(Rl(50.0)).into()),
+        ]);
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        assert_eq!(rectangle(&tree, "Root/A"), Rectangle2D::new().with_pos((15.0, 10.0)).with_size((50.0, 50.0)));
+        assert_eq!(rectangle(&tree, "Root/B"), Rectangle2D::new().with_pos((65.0, 10.0)).with_size((30.0, 30.0)));
+        assert_eq!(rectangle(&tree, "Root/C"), Rectangle2D::new().with_pos((10.0, 60.0)).with_size((250.0, 20.0)));
+    }
+
+    #[test]
+    fn div_nested_content() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(500.0)).into()),
+            ("Root/List", Layout::div().pad(Ab(10.0)).into()),
+            ("Root/List/A", Layout::div().min(Ab(40.0)).into()),
+            ("Root/List/B", Layout::div().min(Ab(40.0)).into()),
+            ("Root/List/B/Image", Layout::window().size(Rl(50.0)).into()),
+        ]);
+        tree.borrow_node_mut("Root/List").unwrap().obtain_data_mut().unwrap().stack = UiStack::new().direction(StackDirection::Vertical).gap(Ab(5.0));
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        assert_eq!(rectangle(&tree, "Root/List"), Rectangle2D::new().with_size((60.0, 105.0)));
+        assert_eq!(rectangle(&tree, "Root/List/B"), Rectangle2D::new().with_pos((10.0, 55.0)).with_size((40.0, 40.0)));
+        assert_eq!(rectangle(&tree, "Root/List/B/Image"), Rectangle2D::new().with_pos((10.0, 55.0)).with_size((20.0, 20.0)));
+    }
+
+    #[test]
+    fn div_grid_content() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(500.0)).into()),
+            ("Root/Panel", Layout::div().pad(Ab(10.0)).into()),
+            ("Root/Panel/A", Layout::grid().into()),
+            ("Root/Panel/B", Layout::grid().into()),
+            ("Root/Panel/C", Layout::grid().into()),
+        ]);
+        tree.borrow_node_mut("Root/Panel").unwrap().obtain_data_mut().unwrap().stack = UiStack::new().grid(UiGrid::new().columns([Ab(20.0), Ab(30.0)]).rows([Ab(10.0)]).gap(Ab(5.0)));
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        // The grid is measured as content, implicit rows included
+        assert_eq!(rectangle(&tree, "Root/Panel"), Rectangle2D::new().with_size((75.0, 45.0)));
+        assert_eq!(rectangle(&tree, "Root/Panel/C"), Rectangle2D::new().with_pos((10.0, 25.0)).with_size((20.0, 10.0)));
+    }
+
+    #[test]
+    fn grid_without_grid() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(500.0)).into()),
+            ("Root/A", Layout::div().min(Ab(40.0)).into()),
+            ("Root/B", Layout::grid().into()),
+            ("Root/B/Content", Layout::div().min(Ab(20.0)).into()),
+        ]);
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        // The parent has no grid, so the Grid node flows like a Div sized by its content
+        assert_eq!(rectangle(&tree, "Root/B"), Rectangle2D::new().with_pos((40.0, 0.0)).with_size((20.0, 20.0)));
+        assert_eq!(rectangle(&tree, "Root/B/Content"), Rectangle2D::new().with_pos((40.0, 0.0)).with_size((20.0, 20.0)));
+    }
+
+    #[test]
+    fn div_wrap() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(100.0)).into()),
+            ("Root/A", Layout::div().min(Ab(60.0)).into()),
+            ("Root/B", Layout::div().min(Ab(60.0)).into()),
+        ]);
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        assert_eq!(rectangle(&tree, "Root/B"), Rectangle2D::new().with_pos((0.0, 60.0)).with_size((60.0, 60.0)));
+    }
+
+    #[test]
+    fn div_stack() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(100.0)).into()),
+            ("Root/A", Layout::div().min(Ab(20.0)).into()),
+            ("Root/B", Layout::div().min(Ab(20.0)).margin_l(Ab(5.0)).into()),
+        ]);
+        let root = tree.borrow_node_mut("Root").unwrap().obtain_data_mut().unwrap();
+        root.stack = UiStack::new().margin(StackMargin::Between);
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        assert_eq!(rectangle(&tree, "Root/A"), Rectangle2D::new().with_size((20.0, 20.0)));
+        assert_eq!(rectangle(&tree, "Root/B"), Rectangle2D::new().with_pos((80.0, 0.0)).with_size((20.0, 20.0)));
+
+        let root = tree.borrow_node_mut("Root").unwrap().obtain_data_mut().unwrap();
+        root.stack = UiStack::new().margin(StackMargin::Around).inverted(true).flipped(true);
+        tree.mark_dirty("Root").unwrap();
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        assert_eq!(rectangle(&tree, "Root/A"), Rectangle2D::new().with_pos((66.25, 80.0)).with_size((20.0, 20.0)));
+        assert_eq!(rectangle(&tree, "Root/B"), Rectangle2D::new().with_pos((18.75, 80.0)).with_size((20.0, 20.0)));
+    }
+
+    #[test]
+    fn div_space() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(100.0)).into()),
+            ("Root/A", Layout::div().min(Ab(20.0)).margin_l(Sp(1.0)).into()),
+            ("Root/B", Layout::div().min(Ab(20.0)).margin_l(Sp(2.0)).margin_y(Sp(1.0)).into()),
+        ]);
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        assert_eq!(rectangle(&tree, "Root/A"), Rectangle2D::new().with_pos((20.0, 0.0)).with_size((20.0, 20.0)));
+        assert_eq!(rectangle(&tree, "Root/B"), Rectangle2D::new().with_pos((80.0, 40.0)).with_size((20.0, 20.0)));
+    }
+
+    #[test]
+    fn grid() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(100.0)).into()),
+            ("Root/A", Layout::grid().cell((1, 0)).span((2, 1)).into()),
+            ("Root/B", Layout::grid().into()),
+            ("Root/C", Layout::grid().into()),
+            ("Root/D", Layout::grid().into()),
+        ]);
+        let root = tree.borrow_node_mut("Root").unwrap().obtain_data_mut().unwrap();
+        root.stack = UiStack::new().grid(UiGrid::new().columns([Ab(20.0).into(), GridTrack::fr(1.0), GridTrack::fr(2.0)]).rows([Ab(30.0)]).gap(Ab(10.0)));
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        assert_eq!(rectangle(&tree, "Root/A"), Rectangle2D::new().with_pos((30.0, 0.0)).with_size((70.0, 30.0)));
+        assert_eq!(rectangle(&tree, "Root/B"), Rectangle2D::new().with_size((20.0, 30.0)));
+        assert_eq!(rectangle(&tree, "Root/D"), Rectangle2D::new().with_pos((30.0, 40.0)).with_size((20.0, 30.0)));
+    }
+
+    #[test]
+    fn dirty() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(100.0)).into()),
+            ("Root/A", Layout::window().size(Ab(10.0)).into()),
+            ("Root/B", Layout::window().size(Ab(10.0)).into()),
+        ]);
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        // Only the marked node is recomputed
+        tree.borrow_node_mut("Root/A").unwrap().obtain_data_mut().unwrap().layout.insert(0, Layout::window().size(Ab(20.0)).into());
+        tree.borrow_node_mut("Root/B").unwrap().obtain_data_mut().unwrap().layout.insert(0, Layout::window().size(Ab(20.0)).into());
+        tree.mark_dirty("Root/A").unwrap();
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        assert_eq!(rectangle(&tree, "Root/A").size, (20.0, 20.0).into());
+        assert_eq!(rectangle(&tree, "Root/B").size, (10.0, 10.0).into());
+        assert!(!tree.borrow_node("Root").unwrap().obtain_data().unwrap().dirty_subnodes);
+    }
+
+    #[test]
+    fn blend() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(100.0)).into()),
+            ("Root/A", Layout::window().size(Ab(10.0)).into()),
+        ]);
+        {
+            let data = tree.borrow_node_mut("Root/A").unwrap().obtain_data_mut().unwrap();
+            data.layout.insert(1, Layout::window().size(Ab(20.0)).into());
+            data.layout.insert(2, Layout::window().pos(Ab(10.0)).size(Ab(6.0)).into());
+            data.layout_blend = vec![(1, 0.5), (2, 1.0)];
+        }
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        // Differences from the base layout are weighted and added together
+        assert_eq!(rectangle(&tree, "Root/A").pos, (10.0, 10.0).into());
+        assert_eq!(rectangle(&tree, "Root/A").size, (11.0, 11.0).into());
+    }
+
+    #[test]
+    fn scroll() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().pos(Ab(10.0)).size(Ab(100.0)).into()),
+            ("Root/A", Layout::window().y(Ab(150.0)).size(Ab(20.0)).into()),
+        ]);
+        tree.borrow_node_mut("Root").unwrap().obtain_data_mut().unwrap().scroll = Some(Vec2::new(0.0, 30.0));
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+
+        // Subnodes are offset by the scroll and clipped to the container
+        assert_eq!(rectangle(&tree, "Root/A").pos, (10.0, 130.0).into());
+        let root = tree.borrow_node("Root").unwrap().obtain_data().unwrap();
+        assert_eq!(root.content_extent, (20.0, 170.0).into());
+        let clip = tree.borrow_node("Root/A").unwrap().obtain_data().unwrap().clip;
+        assert_eq!(clip, Some(Rectangle2D::new().with_pos((10.0, 10.0)).with_size((100.0, 100.0))));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut tree = tree(&[
+            ("Root", Layout::window().size(Ab(100.0)).into()),
+            ("Root/Panel", Layout::window().pos(Ab(10.0)).size(Ab(50.0)).into()),
+            ("Root/Panel/Inside", Layout::window().size(Ab(20.0)).into()),
+            ("Root/Panel/Outside", Layout::window().pos(Ab(40.0)).size(Ab(20.0)).into()),
+            ("Root/Panel/Outside/Child", Layout::window_full().into()),
+        ]);
+        let clip = |tree: &UiTree, path: &str| tree.borrow_node(path).unwrap().obtain_data().unwrap().clip;
+
+        // Visible overflow does not clip
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+        assert_eq!(clip(&tree, "Root/Panel/Outside"), None);
+
+        // Clipped subnodes and everything below them get the panel rectangle
+        let panel = Rectangle2D::new().with_pos((10.0, 10.0)).with_size((50.0, 50.0));
+        tree.borrow_node_mut("Root/Panel").unwrap().obtain_data_mut().unwrap().overflow = UiOverflow::Clip;
+        tree.mark_dirty("Root/Panel").unwrap();
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+        assert_eq!(clip(&tree, "Root/Panel/Inside"), Some(panel));
+        assert_eq!(clip(&tree, "Root/Panel/Outside/Child"), Some(panel));
+
+        // Hidden overflow hides subnodes sticking out together with their subnodes
+        tree.borrow_node_mut("Root/Panel").unwrap().obtain_data_mut().unwrap().overflow = UiOverflow::Hidden;
+        tree.mark_dirty("Root/Panel").unwrap();
+        tree.compute(Rectangle2D::new().with_size((1000.0, 1000.0)).into());
+        assert_eq!(clip(&tree, "Root/Panel/Inside"), Some(panel));
+        assert_eq!(clip(&tree, "Root/Panel/Outside").unwrap().size, Vec2::ZERO);
+        assert_eq!(clip(&tree, "Root/Panel/Outside/Child").unwrap().size, Vec2::ZERO);
+    }
 }
- */
\ No newline at end of file
@@ -80,6 +80,16 @@ impl Rectangle2D {
         self.size.y = height;
         self
     }    
+    /// Returns the area both rectangles cover. Has `0` size if they don't overlap.
+    pub fn intersect(self, rhs: Self) -> Self {
+        let pos = self.pos.max(rhs.pos);
+        let end = (self.pos + self.size).min(rhs.pos + rhs.size);
+        Rectangle2D { pos, size: (end - pos).max(Vec2::ZERO) }
+    }
+    /// Checks if the other rectangle is completely inside of this one.
+    pub fn contains(&self, rhs: Self) -> bool {
+        rhs.pos.cmpge(self.pos).all() && (rhs.pos + rhs.size).cmple(self.pos + self.size).all()
+    }
 }
 impl From<Rectangle2D> for Rectangle3D {
     fn from(val: Rectangle2D) -> Self {
@@ -211,6 +221,25 @@ impl <T> NiceDisplay for MasterData<T> {
 }
 
 
+/// **Ui overflow** - Defines what happens with subnodes that stick out of the node.
+/// Clipping accumulates down the hierarchy, so a subnode is clipped by all of its clipping ancestors.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::UiOverflow;
+/// let overflow = UiOverflow::Clip;
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component)]
+pub enum UiOverflow {
+    /// Subnodes are not clipped by this node.
+    #[default]
+    Visible,
+    /// Subnodes are cropped to the rectangle of this node.
+    Clip,
+    /// Subnodes that are not completely inside the rectangle of this node are hidden.
+    Hidden,
+}
+
+
 /// A struct holding all data appended to [`UiNode`]. Responsible for storing layout, custom data, cache, etc.
 /// Every [`UiNode`] needs to have this to work properly.
 #[derive(Component, Debug, Clone, PartialEq)]
@@ -221,8 +250,12 @@ pub struct NodeData<N:Default + Component> {
     pub rectangle: Rectangle3D,
     /// Layouts of this node.
     pub layout: HashMap<usize, Layout>,
+    /// Indexes of the base layout and the layout to tween to.
     pub layout_index: [usize; 2],
+    /// The tween between the two layouts in `layout_index`.
     pub layout_tween: f32,
+    /// Additional layouts and their weights. Their difference from the base layout is added on top of it.
+    pub layout_blend: Vec<(usize, f32)>,
 
     /// Layout of subnodes and how to stack them.
     pub stack: UiStack,
@@ -232,6 +265,19 @@ pub struct NodeData<N:Default + Component> {
     pub depth_bias: f32,
     /// Size of the content to wrap around. Affects this node's size only if the layout is parametric (Div).
     pub content_size: Vec2,
+    /// What happens with subnodes that stick out of this node.
+    pub overflow: UiOverflow,
+    /// Offset the subnodes are scrolled by. Nodes with it are scroll containers and clip their subnodes, unless the overflow is set.
+    pub scroll: Option<Vec2>,
+    /// Computed size of the area the subnodes span, measured from the unscrolled position of this node.
+    pub content_extent: Vec2,
+    /// Computed rectangle this node is clipped to by its ancestors. Hidden nodes have it with `0` size.
+    pub clip: Option<Rectangle2D>,
+
+    /// If this node needs to be recomputed. Cleared after the compute.
+    pub dirty: bool,
+    /// If any of the subnodes needs to be recomputed. Cleared after the compute.
+    pub dirty_subnodes: bool,
 }
 impl <N:Default + Component> Default for NodeData<N> {
     fn default() -> Self {
@@ -241,10 +287,17 @@ impl <N:Default + Component> Default for NodeData<N> {
             layout: HashMap::from([(0, Layout::default())]),
             layout_index: Default::default(),
             layout_tween: Default::default(),
+            layout_blend: Default::default(),
             stack: Default::default(),
             font_size: Default::default(),
             depth_bias: Default::default(),
             content_size: Default::default(),
+            overflow: Default::default(),
+            scroll: Default::default(),
+            content_extent: Default::default(),
+            clip: Default::default(),
+            dirty: true,
+            dirty_subnodes: false,
         }
     }
 }
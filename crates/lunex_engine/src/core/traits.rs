@@ -6,7 +6,7 @@ use crate::nodes::prelude::*;
 use crate::MasterData;
 use crate::import::*;
 
-use super::{UiNode, UiTree, NodeData};
+use super::{UiNode, UiTree, NodeData, mark_dirty};
 
 
 // #===================#
@@ -50,6 +50,7 @@ impl YInvert for Vec4 {
 
 /// Trait that abstracts over [`NodeCreationTrait`] to provide tailored
 /// implementations for the primitive in layouting context.
+/// Mutable methods mark the returned node as dirty for [`crate::UiNodeTreeComputeTrait::compute`].
 pub trait UiNodeCreationTrait<N:Default + Component> {
     /// Makes new subnode in this node and returns the new subnodes' name.
     /// ## 📌 Note
@@ -103,12 +104,18 @@ impl <N: Default + Component> UiNodeCreationTrait<N> for UiNode<N> {
     fn make_ui_node(&mut self, name: impl Borrow<str>) -> Result<String, NodeError> {
         let n = self.make_node(name)?;
         self.insert_data(n.clone(), NodeData::default())?;
+        mark_dirty(self, &n)?;
         Ok(n)
     }
     fn create_ui_node(&mut self, path: impl Borrow<str>) -> Result<String, NodeError> {
         let mut node: UiNode<N> = Node::new();
         node.add_data(NodeData::default());
-        self.insert_node(path, Node::new())
+        let n = self.insert_node(path.borrow(), node)?;
+        match path.borrow().rsplit_once('/') {
+            None => mark_dirty(self, &n)?,
+            Some((rempath, _)) => mark_dirty(self, &format!("{rempath}/{n}"))?,
+        }
+        Ok(n)
     }
     fn obtain_or_create_ui_node(&mut self, name: impl Borrow<str>) -> Result<&UiNode<N>, NodeError> {
         if let Ok(n) = self.make_ui_node(name.borrow()) {
@@ -120,6 +127,7 @@ impl <N: Default + Component> UiNodeCreationTrait<N> for UiNode<N> {
         if let Ok(n) = self.make_ui_node(name.borrow()) {
             return self.obtain_node_mut(n)
         }
+        mark_dirty(self, name.borrow())?;
         self.obtain_node_mut(name)
     }
     fn borrow_or_create_ui_node(&mut self, path: impl Borrow<str>) -> Result<&UiNode<N>, NodeError> {
@@ -131,7 +139,12 @@ impl <N: Default + Component> UiNodeCreationTrait<N> for UiNode<N> {
     fn borrow_or_create_ui_node_mut(&mut self, path: impl Borrow<str>) -> Result<&mut UiNode<N>, NodeError> {
         match path.borrow().split_once('/') {
             None => self.obtain_or_create_ui_node_mut(path),
-            Some((name, rempath)) => self.obtain_or_create_ui_node_mut(name)?.borrow_or_create_ui_node_mut(rempath),
+            Some((name, rempath)) => {
+                // Only the last node is marked dirty, the nodes along the path are marked as having dirty subnodes
+                if let Some(node_data) = &mut self.data { node_data.dirty_subnodes = true }
+                let _ = self.make_ui_node(name);
+                self.obtain_node_mut(name)?.borrow_or_create_ui_node_mut(rempath)
+            },
         }
     }
 }
@@ -972,6 +972,8 @@ impl Ab<Vec4> {
 pub trait UiValueEvaluate<T> {
     /// Evaluates the NodeSize for `(T)`
     fn evaluate(&self, absolute_scale: T, parent_size: T, viewport_size: T, font_size: T) -> T;
+    /// Evaluates the [`Sp`] weights for `(T)`. They are not part of [`UiValueEvaluate::evaluate`], because the space is distributed by the layout.
+    fn evaluate_sp(&self) -> T;
 }
 
 // # Impl evaluate
@@ -987,6 +989,9 @@ impl UiValueEvaluate<f32> for UiValue<f32> {
         if let Some(v) = self.vh { out += (v/100.0) * viewport_size }
         out
     }
+    fn evaluate_sp(&self) -> f32 {
+        self.sp.unwrap_or(0.0)
+    }
 }
 impl UiValueEvaluate<Vec2> for UiValue<Vec2> {
     fn evaluate(&self, absolute_scale: Vec2, parent_size: Vec2, viewport_size: Vec2, font_size: Vec2) -> Vec2 {
@@ -1000,6 +1005,9 @@ impl UiValueEvaluate<Vec2> for UiValue<Vec2> {
         if let Some(v) = self.vh { out += (v/100.0) * viewport_size.y }
         out
     }
+    fn evaluate_sp(&self) -> Vec2 {
+        self.sp.unwrap_or(Vec2::ZERO)
+    }
 }
 impl UiValueEvaluate<Vec3> for UiValue<Vec3> {
     fn evaluate(&self, absolute_scale: Vec3, parent_size: Vec3, viewport_size: Vec3, font_size: Vec3) -> Vec3 {
@@ -1013,6 +1021,9 @@ impl UiValueEvaluate<Vec3> for UiValue<Vec3> {
         if let Some(v) = self.vh { out += (v/100.0) * viewport_size.y }
         out
     }
+    fn evaluate_sp(&self) -> Vec3 {
+        self.sp.unwrap_or(Vec3::ZERO)
+    }
 }
 impl UiValueEvaluate<Vec4> for UiValue<Vec4> {
     fn evaluate(&self, absolute_scale: Vec4, parent_size: Vec4, viewport_size: Vec4, font_size: Vec4) -> Vec4 {
@@ -1026,6 +1037,9 @@ impl UiValueEvaluate<Vec4> for UiValue<Vec4> {
         if let Some(v) = self.vh { out += (v/100.0) * viewport_size.y }
         out
     }
+    fn evaluate_sp(&self) -> Vec4 {
+        self.sp.unwrap_or(Vec4::ZERO)
+    }
 }
 
 
@@ -0,0 +1,128 @@
+use crate::import::*;
+use crate::{NiceDisplay, UiValue, UiValueEvaluate};
+
+
+// #=======================#
+// #=== GRID PROPERTIES ===#
+
+/// **Grid track** - A type used to define the size of a single grid column or row.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::{GridTrack, Ab, Rl};
+/// let track: GridTrack = Ab(100.0).into();      // -> 100px wide track
+/// let track: GridTrack = Rl(20.0).into();       // -> 20% of the grid wide track
+/// let track: GridTrack = GridTrack::fr(1.0);    // -> 1 fraction of the space left
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridTrack {
+    /// Track with a fixed size.
+    Size(UiValue<f32>),
+    /// Track that takes a fraction of the space left after all fixed tracks, same as `fr` in CSS.
+    Fr(f32),
+}
+impl GridTrack {
+    /// Creates new fractional track.
+    pub fn fr(value: f32) -> Self {
+        GridTrack::Fr(value)
+    }
+}
+impl Default for GridTrack {
+    fn default() -> Self {
+        GridTrack::Fr(1.0)
+    }
+}
+impl <T: Into<UiValue<f32>>> From<T> for GridTrack {
+    fn from(val: T) -> Self {
+        GridTrack::Size(val.into())
+    }
+}
+impl NiceDisplay for GridTrack {
+    fn to_nicestr(&self) -> String {
+        match self {
+            GridTrack::Size(size) => size.to_nicestr(),
+            GridTrack::Fr(fr) => format!("{}{}", fr, "fr"),
+        }
+    }
+}
+
+
+// #================#
+// #=== THE GRID ===#
+
+/// **Ui grid** - A type used to define the columns and rows subnodes with [`crate::Grid`] layout are placed into.
+/// If there are more subnodes than cells, new tracks with the size of the last track are added in the [`crate::UiStack`] cross direction.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::{UiGrid, GridTrack, Ab};
+/// let grid = UiGrid::new().columns([GridTrack::fr(1.0); 4]).rows([Ab(64.0)]).gap(Ab(8.0));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UiGrid {
+    /// Sizes of the columns.
+    pub columns: Vec<GridTrack>,
+    /// Sizes of the rows.
+    pub rows: Vec<GridTrack>,
+    /// Gap between the columns and rows.
+    pub gap: UiValue<Vec2>,
+}
+impl UiGrid {
+    /// Creates new empty Grid.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the columns with new values.
+    pub fn columns<T: Into<GridTrack>>(mut self, columns: impl IntoIterator<Item = T>) -> Self {
+        self.columns = columns.into_iter().map(|track| track.into()).collect();
+        self
+    }
+    /// Replaces the rows with new values.
+    pub fn rows<T: Into<GridTrack>>(mut self, rows: impl IntoIterator<Item = T>) -> Self {
+        self.rows = rows.into_iter().map(|track| track.into()).collect();
+        self
+    }
+    /// Replaces the gap with a new value.
+    pub fn gap(mut self, gap: impl Into<UiValue<Vec2>>) -> Self {
+        self.gap = gap.into();
+        self
+    }
+    /// Sets the columns to new values.
+    pub fn set_columns<T: Into<GridTrack>>(&mut self, columns: impl IntoIterator<Item = T>) {
+        self.columns = columns.into_iter().map(|track| track.into()).collect();
+    }
+    /// Sets the rows to new values.
+    pub fn set_rows<T: Into<GridTrack>>(&mut self, rows: impl IntoIterator<Item = T>) {
+        self.rows = rows.into_iter().map(|track| track.into()).collect();
+    }
+    /// Sets the gap to a new value.
+    pub fn set_gap(&mut self, gap: impl Into<UiValue<Vec2>>) {
+        self.gap = gap.into();
+    }
+}
+
+/// Computes the offsets and sizes of the tracks. The last track is repeated until there is `count` of them.
+pub(crate) fn compute_tracks(tracks: &[GridTrack], count: usize, gap: f32, size: f32, absolute_scale: f32, viewport_size: f32, font_size: f32) -> Vec<(f32, f32)> {
+    let last = tracks.last().copied().unwrap_or_default();
+    let tracks: Vec<GridTrack> = (0..count.max(tracks.len())).map(|i| tracks.get(i).copied().unwrap_or(last)).collect();
+
+    // Fixed tracks take their space first, fractions share what is left
+    let mut fixed = gap * (tracks.len().max(1) - 1) as f32;
+    let mut fractions = 0.0;
+    for track in &tracks {
+        match track {
+            GridTrack::Size(value) => fixed += value.evaluate(absolute_scale, size, viewport_size, font_size),
+            GridTrack::Fr(fr) => fractions += fr,
+        }
+    }
+    let unit = if fractions > 0.0 { (size - fixed).max(0.0) / fractions } else { 0.0 };
+
+    let mut cursor = 0.0;
+    tracks.iter().map(|track| {
+        let length = match track {
+            GridTrack::Size(value) => value.evaluate(absolute_scale, size, viewport_size, font_size),
+            GridTrack::Fr(fr) => fr * unit,
+        };
+        let out = (cursor, length);
+        cursor += length + gap;
+        out
+    }).collect()
+}
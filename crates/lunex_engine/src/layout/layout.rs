@@ -21,6 +21,7 @@ pub enum Layout {
     Window(Window),
     Solid(Solid),
     Div(Div),
+    Grid(Grid),
 }
 impl Layout {
 
@@ -81,6 +82,17 @@ impl Layout {
         Div::new()
     }
 
+    /// **Grid** - Parametric layout type that is defined by the cell and span. Its location and size is based on
+    /// the [`crate::UiGrid`] of the parent [`crate::UiStack`]. Nodes without a cell are placed into the next free cell.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use lunex_engine::UiLayout;
+    /// let layout: UiLayout = UiLayout::grid().cell((1, 0)).span((2, 1)).pack();
+    /// ```
+    pub fn grid() -> Grid {
+        Grid::new()
+    }
+
     /// Unwrap the type, panic if not Boundary variant
     pub fn expect_boundary(&self) -> &Boundary {
         match self {
@@ -137,6 +149,20 @@ impl Layout {
             _ => panic!("A different layout type than expected! Got {}, expected Div", self.to_nicestr())
         }
     }
+    /// Unwrap the type, panic if not Grid variant
+    pub fn expect_grid(&self) -> &Grid {
+        match self {
+            Layout::Grid(g) => g,
+            _ => panic!("A different layout type than expected! Got {}, expected Grid", self.to_nicestr())
+        }
+    }
+    /// Unwrap the type, panic if not Grid variant
+    pub fn expect_grid_mut(&mut self) -> &mut Grid {
+        match self {
+            Layout::Grid(g) => g,
+            _ => panic!("A different layout type than expected! Got {}, expected Grid", self.to_nicestr())
+        }
+    }
 }
 impl Default for Layout {
     fn default() -> Self {
@@ -150,6 +176,7 @@ impl NiceDisplay for Layout {
             Layout::Solid(layout) => format!("{} {}", "Solid".bold().bright_cyan(), layout.to_nicestr()),
             Layout::Window(layout) => format!("{} {}", "Window".bold().bright_cyan(), layout.to_nicestr()),
             Layout::Div(layout) => format!("{} {}", "Div".bold().bright_cyan(), layout.to_nicestr()),
+            Layout::Grid(layout) => format!("{} {}", "Grid".bold().bright_cyan(), layout.to_nicestr()),
         }
     }
 }
@@ -299,7 +326,7 @@ impl NiceDisplay for Scaling {
 pub enum Sizing {
     /// Div node layout should be as small as possible.
     Min,
-    /// Div node layout should 
+    /// Div node layout should be as big as its content.
     #[default] Basic,
     /// Div node layout should be as big as possible.
     Max,
@@ -732,7 +759,7 @@ impl Div {
     }
     /// Replaces the minimal width with a new value.
     pub fn min_width(mut self, size: impl Into<UiValue<f32>>) -> Self {
-        if let Some(mut minsize) = self.min_size {
+        if let Some(minsize) = &mut self.min_size {
             minsize.set_x(size.into())
         } else {
             self.min_size = Some(UiValue::<Vec2>::new().with_x(size.into()));
@@ -741,7 +768,7 @@ impl Div {
     }
     /// Replaces the minimal height with a new value.
     pub fn min_height(mut self, size: impl Into<UiValue<f32>>) -> Self {
-        if let Some(mut minsize) = self.min_size {
+        if let Some(minsize) = &mut self.min_size {
             minsize.set_y(size.into())
         } else {
             self.min_size = Some(UiValue::<Vec2>::new().with_y(size.into()));
@@ -755,7 +782,7 @@ impl Div {
     }
     /// Replaces the maximal width with a new value.
     pub fn max_width(mut self, size: impl Into<UiValue<f32>>) -> Self {
-        if let Some(mut maxsize) = self.max_size {
+        if let Some(maxsize) = &mut self.max_size {
             maxsize.set_x(size.into())
         } else {
             self.max_size = Some(UiValue::<Vec2>::new().with_x(size.into()));
@@ -764,7 +791,7 @@ impl Div {
     }
     /// Replaces the maximal height with a new value.
     pub fn max_height(mut self, size: impl Into<UiValue<f32>>) -> Self {
-        if let Some(mut maxsize) = self.max_size {
+        if let Some(maxsize) = &mut self.max_size {
             maxsize.set_y(size.into())
         } else {
             self.max_size = Some(UiValue::<Vec2>::new().with_y(size.into()));
@@ -913,7 +940,7 @@ impl Div {
     }
     /// Sets the minimal width to a new value.
     pub fn set_min_width(&mut self, size: impl Into<UiValue<f32>>) {
-        if let Some(mut minsize) = self.min_size {
+        if let Some(minsize) = &mut self.min_size {
             minsize.set_x(size.into())
         } else {
             self.min_size = Some(UiValue::<Vec2>::new().with_x(size.into()));
@@ -921,7 +948,7 @@ impl Div {
     }
     /// Sets the minimal height to a new value.
     pub fn set_min_height(&mut self, size: impl Into<UiValue<f32>>) {
-        if let Some(mut minsize) = self.min_size {
+        if let Some(minsize) = &mut self.min_size {
             minsize.set_y(size.into())
         } else {
             self.min_size = Some(UiValue::<Vec2>::new().with_y(size.into()));
@@ -933,7 +960,7 @@ impl Div {
     }
     /// Sets the maximal width to a new value.
     pub fn set_max_width(&mut self, size: impl Into<UiValue<f32>>) {
-        if let Some(mut maxsize) = self.max_size {
+        if let Some(maxsize) = &mut self.max_size {
             maxsize.set_x(size.into())
         } else {
             self.max_size = Some(UiValue::<Vec2>::new().with_x(size.into()));
@@ -941,7 +968,7 @@ impl Div {
     }
     /// Sets the maximal height to a new value.
     pub fn set_max_height(&mut self, size: impl Into<UiValue<f32>>) {
-        if let Some(mut maxsize) = self.max_size {
+        if let Some(maxsize) = &mut self.max_size {
             maxsize.set_y(size.into())
         } else {
             self.max_size = Some(UiValue::<Vec2>::new().with_y(size.into()));
@@ -1050,6 +1077,49 @@ impl Div {
         self.margin.set_w(margin);
     }
 
+    /// Computes the padding based on given parameters.
+    pub(crate) fn compute_padding(&self, parent_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Vec4 {
+        self.padding.evaluate(Vec4::splat(absolute_scale), parent_size.extend(parent_size.x).extend(parent_size.y), viewport_size.extend(viewport_size.x).extend(viewport_size.y), Vec4::splat(font_size))
+    }
+    /// Computes the border based on given parameters.
+    pub(crate) fn compute_border(&self, parent_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Vec4 {
+        self.border.evaluate(Vec4::splat(absolute_scale), parent_size.extend(parent_size.x).extend(parent_size.y), viewport_size.extend(viewport_size.x).extend(viewport_size.y), Vec4::splat(font_size))
+    }
+    /// Computes the margin based on given parameters.
+    pub(crate) fn compute_margin(&self, parent_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Vec4 {
+        self.margin.evaluate(Vec4::splat(absolute_scale), parent_size.extend(parent_size.x).extend(parent_size.y), viewport_size.extend(viewport_size.x).extend(viewport_size.y), Vec4::splat(font_size))
+    }
+    /// Computes the [`Sp`] weights of the margin.
+    pub(crate) fn compute_margin_sp(&self) -> Vec4 {
+        self.margin.evaluate_sp()
+    }
+    /// Computes the size of the node based on its content and the space available in the parent.
+    /// A component of `max_size` that evaluates to `0` or less is treated as unlimited.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn compute_size(&self, content: Vec2, padding: Vec4, border: Vec4, available: Vec2, parent_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Vec2 {
+        let basic = content + padding.xy() + padding.zw() + border.xy() + border.zw();
+        let min = if let Some(min) = self.min_size { min.evaluate(Vec2::splat(absolute_scale), parent_size, viewport_size, Vec2::splat(font_size)) } else { Vec2::ZERO };
+        let max = if let Some(max) = self.max_size { max.evaluate(Vec2::splat(absolute_scale), parent_size, viewport_size, Vec2::splat(font_size)) } else { Vec2::ZERO };
+
+        let mut size = Vec2::new(
+            match self.width {
+                Sizing::Min => if min.x > 0.0 { min.x } else { basic.x },
+                Sizing::Basic => basic.x,
+                Sizing::Max => available.x,
+            },
+            match self.height {
+                Sizing::Min => if min.y > 0.0 { min.y } else { basic.y },
+                Sizing::Basic => basic.y,
+                Sizing::Max => available.y,
+            },
+        );
+
+        // Minimal size takes priority over the maximal size
+        if max.x > 0.0 { size.x = size.x.min(max.x) }
+        if max.y > 0.0 { size.y = size.y.min(max.y) }
+        size.max(min).max(Vec2::ZERO)
+    }
+
     /// Packs the struct into Layout
     pub fn package(self) -> Layout {
         self.into()
@@ -1066,3 +1136,70 @@ impl NiceDisplay for Div {
         format!("{}", t.black())
     }
 }
+
+/// **Grid** - Parametric layout type that is defined by the cell and span. Its location and size is based on
+/// the [`crate::UiGrid`] of the parent [`crate::UiStack`]. Nodes without a cell are placed into the next free cell.
+/// If the parent has no grid, the node is placed like a default [`Div`] instead.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::Grid;
+/// let layout: UiLayout = Grid::new().cell((1, 0)).span((2, 1)).pack();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct Grid {
+    /// Optional cell the node is placed into. `x-column`, `y-row`
+    pub cell: Option<UVec2>,
+    /// How many cells the node covers. `x-columns`, `y-rows`
+    pub span: UVec2,
+}
+impl Grid {
+    /// Creates new Grid node layout placed into the next free cell.
+    pub const fn new() -> Self {
+        Grid {
+            cell: None,
+            span: UVec2::ONE,
+        }
+    }
+    /// Replaces the cell with a new value.
+    pub fn cell(mut self, cell: impl Into<UVec2>) -> Self {
+        self.cell = Some(cell.into());
+        self
+    }
+    /// Replaces the span with a new value.
+    pub fn span(mut self, span: impl Into<UVec2>) -> Self {
+        self.span = span.into();
+        self
+    }
+    /// Sets the cell to a new value.
+    pub fn set_cell(&mut self, cell: impl Into<UVec2>) {
+        self.cell = Some(cell.into());
+    }
+    /// Sets the span to a new value.
+    pub fn set_span(&mut self, span: impl Into<UVec2>) {
+        self.span = span.into();
+    }
+
+    /// Packs the struct into Layout
+    pub fn package(self) -> Layout {
+        self.into()
+    }
+}
+impl Default for Grid {
+    fn default() -> Self {
+        Grid::new()
+    }
+}
+impl From<Grid> for Layout {
+    fn from(val: Grid) -> Self {
+        Layout::Grid(val)
+    }
+}
+impl NiceDisplay for Grid {
+    fn to_nicestr(&self) -> String {
+        let t = match self.cell {
+            Some(cell) => format!("[cell: ({}, {}) span: ({}, {})]", cell.x, cell.y, self.span.x, self.span.y),
+            None => format!("[cell: auto span: ({}, {})]", self.span.x, self.span.y),
+        };
+        format!("{}", t.black())
+    }
+}
@@ -5,6 +5,9 @@ pub use layout::*;
 mod stack;
 pub use stack::*;
 
+mod grid;
+pub use grid::*;
+
 // #======================#
 // #=== PRELUDE EXPORT ===#
 
@@ -15,8 +18,11 @@ pub mod prelude {
     pub use super::UiStack;
     pub use super::{StackDirection, StackMargin};
 
+    pub use super::UiGrid;
+    pub use super::GridTrack;
+
     #[allow(non_snake_case)]
     pub mod ui {
-        pub use super::super::{Boundary, Window, Solid, Div};
+        pub use super::super::{Boundary, Window, Solid, Div, Grid};
     }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use crate::import::*;
 use crate::UiValue;
+use crate::UiGrid;
 
 
 // #========================#
@@ -14,33 +15,34 @@ pub enum StackDirection {
 }
 
 
-/// **Stack margin** - A special type to define margin subnodes should inherit. Contains a set of presets.
+/// **Stack margin** - A special type to define how the free space on each line is distributed between subnodes.
+/// The presets work the same as `justify-content` in CSS flexbox, subnodes inherit `1sp` margins on the sides that receive the free space.
 /// ## 🛠️ Example
 /// ```
 /// # use lunex_engine::StackMargin;
-/// let margin = StackMargin::Start;  // -> Default, does nothing
-/// let margin = StackMargin::Center; // -> Subnodes on sides will inherit 1sp on sides facing out
-/// let margin = StackMargin::End;    // -> First subnode will inherit 1sp on left side
-/// let margin = StackMargin::Between;// -> All subnodes except 1st will inherit 1sp on left side
-/// let margin = StackMargin::Evenly; // -> All subnodes will inherit 1sp on both sides except 1st
-/// let margin = StackMargin::Around; // -> All subnodes will inherit 1sp on both sides
+/// let margin = StackMargin::Start;  // -> Default, subnodes are packed at the start
+/// let margin = StackMargin::Center; // -> Subnodes are packed in the center
+/// let margin = StackMargin::End;    // -> Subnodes are packed at the end
+/// let margin = StackMargin::Between;// -> Free space is only between subnodes
+/// let margin = StackMargin::Evenly; // -> Free space is equal between subnodes and the edges
+/// let margin = StackMargin::Around; // -> Every subnode has equal free space on both sides
 /// ```
 #[derive(Debug, Default, Clone, PartialEq)]
 pub enum StackMargin {
-    /// Default, does nothing.
+    /// Default, subnodes are packed at the start. Same as `flex-start`.
     #[default]
     Start,
-    /// Subnodes on sides will inherit 1sp on sides facing out.
+    /// Subnodes are packed in the center. Same as `center`.
     Center,
-    /// First subnode will inherit 1sp on left side.
+    /// Subnodes are packed at the end. Same as `flex-end`.
     End,
-    /// All subnodes except 1st will inherit 1sp on left side.
+    /// Free space is only between subnodes. Same as `space-between`.
     Between,
-    /// All subnodes will inherit 1sp on both sides except 1st (only left).
+    /// Free space is equal between subnodes and the edges. Same as `space-evenly`.
     Evenly,
-    /// All subnodes will inherit 1sp on both sides.
+    /// Every subnode has equal free space on both sides. Same as `space-around`.
     Around,
-    /// Manually set margin for all subnodes.
+    /// Manually set margin for all subnodes. It is added to the margin of each subnode.
     Manual(Box<UiValue<Vec4>>),
 }
 
@@ -66,6 +68,8 @@ pub struct UiStack {
     pub gap: UiValue<Vec2>,
     /// The margin that subnodes should inherit.
     pub margin: StackMargin,
+    /// Optional grid that subnodes with [`crate::Grid`] layout are placed into. They are auto-placed in the stack direction.
+    pub grid: Option<UiGrid>,
 }
 impl UiStack {
     /// Creates new empty Stack.
@@ -107,6 +111,11 @@ impl UiStack {
         self.margin = margin;
         self
     }
+    /// Replaces the grid with a new value.
+    pub fn grid(mut self, grid: UiGrid) -> Self {
+        self.grid = Some(grid);
+        self
+    }
     /// Sets the direction to a new value.
     pub fn set_direction(&mut self, direction: StackDirection) {
         self.direction = direction;
@@ -135,4 +144,8 @@ impl UiStack {
     pub fn set_margin(&mut self, margin: StackMargin) {
         self.margin = margin;
     }
-}
\ No newline at end of file
+    /// Sets the grid to a new value.
+    pub fn set_grid(&mut self, grid: UiGrid) {
+        self.grid = Some(grid);
+    }
+}
@@ -27,9 +27,10 @@ pub(crate) mod import {
     pub(crate) use bevy::prelude::{Component, Deref, DerefMut};
 
     pub(crate) use indexmap::IndexMap;
-    pub(crate) use bevy::utils::HashMap;
+    pub(crate) use bevy::utils::{HashMap, HashSet};
     pub(crate) use colored::Colorize;
 
-    pub(crate) use bevy::math::{Vec2, Vec3, Vec4};
+    pub(crate) use bevy::math::{Vec2, Vec3, Vec4, UVec2};
+    pub(crate) use bevy::math::{Vec2Swizzles, Vec3Swizzles, Vec4Swizzles};
     pub(crate) use thiserror::Error;
 }
\ No newline at end of file
@@ -258,6 +258,14 @@ pub struct Node<T> {
     pub nodes: IndexMap<String, Node<T>>,
 }
 impl <T> Node<T> {
+    /// Regenerate the cached path and depth of all subnodes recursively
+    pub(crate) fn cascade_path(&mut self) {
+        for (name, node) in &mut self.nodes {
+            node.path = if self.path.is_empty() { name.to_owned() } else { self.path.to_owned() + "/" + name };
+            node.depth = self.depth + 1.0;
+            node.cascade_path();
+        }
+    }
     /// Generate overview of the inner tree and write the mapped output to the given string with data formatted to a certain level depth
     pub(crate) fn cascade_tree(&self, mut string: String, level: u32, param: &str) -> String {
         for (name, node) in &self.nodes {
@@ -312,6 +320,7 @@ impl <T> NodeGeneralTrait<T> for Node<T> {
                 node.name = name.borrow().to_owned();
                 node.path = if self.path.is_empty() { name.borrow().to_string() } else { self.path.to_string() + "/" + name.borrow() };
                 node.depth = self.depth + 1.0;
+                node.cascade_path();
                 self.nodes.insert(name.borrow().to_owned(), node);
                 Ok(name.borrow().to_owned())
             } else {
@@ -328,6 +337,7 @@ impl <T> NodeGeneralTrait<T> for Node<T> {
             node.name = generated_name.to_owned();
             node.path = if self.path.is_empty() { generated_name.to_owned() } else { self.path.to_owned() + "/" + &generated_name };
             node.depth = self.depth + 1.0;
+            node.cascade_path();
             self.nodes.insert(generated_name.to_owned(), node);
             Ok(generated_name)
         }
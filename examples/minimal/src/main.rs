@@ -29,18 +29,16 @@ fn setup(mut cmd: Commands, assets: Res<AssetServer>) {
         },
         SourceFromCamera,
     )).with_children(|ui| {
+        ui!(ui: MainUi {
 
-        // Spawn boundary node
-        ui.spawn((
-            UiLink::<MainUi>::path("Root"),
-            UiLayout::boundary().pos1(Ab(20.0)).pos2(Rl(100.0) - Ab(20.0)).pack::<Base>(),
-        ));
+            // Spawn boundary node
+            "Root": [UiLayout::boundary().pos1(Ab(20.0)).pos2(Rl(100.0) - Ab(20.0))] {
 
-        // Spawn image with a node
-        ui.spawn((
-            UiLink::<MainUi>::path("Root/Rectangle"),
-            UiLayout::solid().size((Ab(1920.0), Ab(1080.0))).pack::<Base>(),
-            UiImage2dBundle::from(assets.load("background.png")),
-        ));
+                // Spawn image with a node
+                "Rectangle": [UiLayout::solid().size((Ab(1920.0), Ab(1080.0)))] (
+                    UiImage2dBundle::from(assets.load("background.png")),
+                ),
+            },
+        });
     });
-}
\ No newline at end of file
+}